@@ -0,0 +1,157 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Ident, ItemStruct, Result};
+
+use crate::enums::Target;
+use crate::props::{Cached, FieldProps, FuncProps};
+
+fn cache_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}Cache", struct_ident)
+}
+
+/// `__cache: ExampleCache`, a slot per cached value.
+pub(crate) fn cache_field(input: &ItemStruct) -> TokenStream {
+    let vis = &input.vis;
+    let cache_ident = cache_ident(&input.ident);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    quote! {
+        #[doc(hidden)]
+        #vis __cache: #cache_ident #ty_generics
+    }
+}
+
+/// Empties the slots of the cached values depending on the field that was written.
+pub(crate) fn invalidate(cached: &[Cached], field_ident: &Ident) -> TokenStream {
+    let names = cached
+        .iter()
+        .filter(|cached| cached.depends_on.contains(field_ident))
+        .map(|cached| &cached.name);
+
+    quote! { #(self.__cache.#names.take();)* }
+}
+
+/// A getter filling its slot on first use, returning `&ty`.
+pub(crate) fn expand_cached_getter(
+    field_map: &[(Ident, FieldProps)],
+    cached: &Cached,
+    func_props: &FuncProps,
+) -> Result<TokenStream> {
+    let FuncProps {
+        name,
+        target,
+        inline,
+        ..
+    } = func_props;
+
+    if let Some(unknown) = cached.depends_on.iter().find(|dependency| {
+        !field_map
+            .iter()
+            .any(|(field_ident, _)| field_ident == *dependency)
+    }) {
+        return Err(Error::new_spanned(
+            unknown,
+            "`depends_on` must name fields of the struct",
+        ));
+    }
+
+    if let Target::SharedTrait(_) = target {
+        return Err(Error::new_spanned(
+            &cached.name,
+            "`shared_trait` is not supported on `cached`",
+        ));
+    }
+
+    let slot = &cached.name;
+    let ty = &cached.ty;
+    let compute = &cached.compute;
+    let doc = cached.doc.as_ref().map(|doc| quote! { #[doc = #doc] });
+
+    let vis = func_props.item_vis();
+
+    Ok(quote! {
+        #doc
+        #inline
+        #vis fn #name(&self) -> &#ty {
+            self.__cache.#slot.get_or_init(|| #compute(self))
+        }
+    })
+}
+
+/// The cache type, and `invalidate_caches` for writes the generated mutators don't see.
+pub(crate) fn expand_cache(input: &ItemStruct, cached: &[Cached]) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let cache_ident = cache_ident(struct_ident);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let names = cached.iter().map(|cached| &cached.name).collect::<Vec<_>>();
+    let tys = cached.iter().map(|cached| &cached.ty);
+
+    // Keeps the struct's generics used, whether or not the cached types use them.
+    let (phantom, phantom_init) = if generics.params.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(
+                quote! { __phantom: ::core::marker::PhantomData<fn() -> #struct_ident #ty_generics>, },
+            ),
+            Some(quote! { __phantom: ::core::marker::PhantomData, }),
+        )
+    };
+
+    let cache_doc = format!(
+        "The cached values of a [`{struct_ident}`]. They are not cloned, and never affect comparisons."
+    );
+
+    quote! {
+        #[doc = #cache_doc]
+        #vis struct #cache_ident #generics #where_clause {
+            #(#names: ::core::cell::OnceCell<#tys>,)*
+            #phantom
+        }
+
+        impl #impl_generics ::core::default::Default for #cache_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#names: ::core::cell::OnceCell::new(),)*
+                    #phantom_init
+                }
+            }
+        }
+
+        impl #impl_generics ::core::clone::Clone for #cache_ident #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                ::core::default::Default::default()
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Debug for #cache_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!(#cache_ident))
+                    #(.field(stringify!(#names), &self.#names.get().is_some()))*
+                    .finish()
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq for #cache_ident #ty_generics #where_clause {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #cache_ident #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::hash::Hash for #cache_ident #ty_generics #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+        }
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Empties every cached value, e.g. after writing to a field directly.
+            #vis fn invalidate_caches(&mut self) {
+                #(self.__cache.#names.take();)*
+            }
+        }
+    }
+}
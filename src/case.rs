@@ -0,0 +1,43 @@
+use proc_macro2::Ident;
+use quote::format_ident;
+use syn::ext::IdentExt;
+
+/// `r#type` -> `"type"`, the name of a field as seen at runtime and in generated names.
+pub(crate) fn field_name(ident: &Ident) -> String {
+    ident.unraw().to_string()
+}
+
+/// `field_name` -> `FieldName`
+pub(crate) fn to_camel_case(ident: &Ident) -> Ident {
+    let camel = field_name(ident)
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+
+    format_ident!("{}", camel, span = ident.span())
+}
+
+/// `StructName` -> `struct_name`
+pub(crate) fn to_snake_case(ident: &Ident) -> Ident {
+    let mut snake = String::new();
+
+    for (i, c) in field_name(ident).char_indices() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    format_ident!("{}", snake, span = ident.span())
+}
@@ -0,0 +1,181 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Ident, MetaNameValue, Result, Type};
+
+use crate::enums::{CollectionFn, Container};
+use crate::hooks::WriteHooks;
+use crate::props::{expect_lit_str, FuncProps};
+use crate::ty::type_args;
+
+/// The container and the item type of a collection field, from its type or
+/// from the `container = ".."` and `item = ".."` settings for aliases.
+pub(crate) fn collection_types(ty: &Type, settings: &[MetaNameValue]) -> Result<(Container, Type)> {
+    let mut container = None;
+    let mut item = None;
+
+    for setting in settings {
+        if setting.path.is_ident("container") {
+            let name = expect_lit_str(setting.value.clone(), "container = \"Vec\"")?;
+            container = Some(Container::from_name(&name.value()).ok_or_else(|| {
+                Error::new_spanned(
+                    &name,
+                    "Valid containers are `Vec`, `VecDeque`, `HashSet` and `BTreeSet`",
+                )
+            })?);
+        } else {
+            item = Some(expect_lit_str(setting.value.clone(), "item = \"T\"")?.parse()?);
+        }
+    }
+
+    let args = type_args(ty, &Container::NAMES);
+    let detected = match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .and_then(|segment| Container::from_name(&segment.ident.to_string())),
+        _ => None,
+    };
+
+    match (
+        container.or(detected),
+        item.or_else(|| args?.first().cloned().cloned()),
+    ) {
+        (Some(container), Some(item)) => Ok((container, item)),
+        _ => Err(Error::new_spanned(
+            ty,
+            "`collection` requires a `Vec`, `VecDeque`, `HashSet` or `BTreeSet` field, \
+             use `collection(container = \"Vec\", item = \"T\")` for aliases",
+        )),
+    }
+}
+
+/// The signature and body of a function of the `collection` preset.
+pub(crate) fn expand_collection_fn(
+    collection_fn: CollectionFn,
+    container: Container,
+    index: usize,
+    field_ident: &Ident,
+    func_props: &FuncProps,
+    hooks: &WriteHooks,
+) -> (TokenStream, TokenStream) {
+    // Set when the flag was parsed.
+    let item = func_props.ty.as_ref().unwrap();
+    let after_write = hooks.after_write(index, field_ident);
+
+    // Sets are looked up by anything their items borrow as.
+    let lookup_bounds = match container {
+        Container::HashSet => quote! { ::core::hash::Hash + ::core::cmp::Eq },
+        _ => quote! { ::core::cmp::Ord },
+    };
+
+    match collection_fn {
+        CollectionFn::Push => match container {
+            Container::Vec | Container::VecDeque => {
+                let push = match container {
+                    Container::Vec => quote! { push },
+                    _ => quote! { push_back },
+                };
+
+                (
+                    quote! { (&mut self, value: #item) },
+                    quote! {
+                        self.#field_ident.#push(value);
+                        #after_write
+                    },
+                )
+            }
+            Container::HashSet | Container::BTreeSet => (
+                quote! { (&mut self, value: #item) -> bool },
+                quote! {
+                    let inserted = self.#field_ident.insert(value);
+                    if inserted {
+                        #after_write
+                    }
+                    inserted
+                },
+            ),
+        },
+        CollectionFn::Extend => (
+            quote! { (&mut self, values: impl ::core::iter::IntoIterator<Item = #item>) },
+            quote! {
+                ::core::iter::Extend::extend(&mut self.#field_ident, values);
+                #after_write
+            },
+        ),
+        CollectionFn::Remove => match container {
+            Container::Vec => (
+                quote! { (&mut self, index: usize) -> ::core::option::Option<#item> },
+                quote! {
+                    if index >= self.#field_ident.len() {
+                        return ::core::option::Option::None;
+                    }
+
+                    let removed = self.#field_ident.remove(index);
+                    #after_write
+                    ::core::option::Option::Some(removed)
+                },
+            ),
+            Container::VecDeque => (
+                quote! { (&mut self, index: usize) -> ::core::option::Option<#item> },
+                quote! {
+                    let removed = self.#field_ident.remove(index);
+                    if removed.is_some() {
+                        #after_write
+                    }
+                    removed
+                },
+            ),
+            Container::HashSet | Container::BTreeSet => (
+                quote! {
+                    <Q: ?::core::marker::Sized + #lookup_bounds>(&mut self, value: &Q) -> bool
+                    where
+                        #item: ::core::borrow::Borrow<Q>
+                },
+                quote! {
+                    let removed = self.#field_ident.remove(value);
+                    if removed {
+                        #after_write
+                    }
+                    removed
+                },
+            ),
+        },
+        CollectionFn::Clear => (
+            quote! { (&mut self) },
+            quote! {
+                self.#field_ident.clear();
+                #after_write
+            },
+        ),
+        CollectionFn::Len => (
+            quote! { (&self) -> usize },
+            quote! { self.#field_ident.len() },
+        ),
+        CollectionFn::IsEmpty => (
+            quote! { (&self) -> bool },
+            quote! { self.#field_ident.is_empty() },
+        ),
+        CollectionFn::Iter => (
+            quote! { (&self) -> impl ::core::iter::Iterator<Item = &#item> + '_ },
+            quote! { self.#field_ident.iter() },
+        ),
+        CollectionFn::Contains if container.is_set() => (
+            quote! {
+                <Q: ?::core::marker::Sized + #lookup_bounds>(&self, value: &Q) -> bool
+                where
+                    #item: ::core::borrow::Borrow<Q>
+            },
+            quote! { self.#field_ident.contains(value) },
+        ),
+        // Generic, so that items without `PartialEq` don't fail the bound.
+        CollectionFn::Contains => (
+            quote! {
+                <Q: ?::core::marker::Sized>(&self, value: &Q) -> bool
+                where
+                    #item: ::core::cmp::PartialEq<Q>
+            },
+            quote! { self.#field_ident.iter().any(|item| item == value) },
+        ),
+    }
+}
@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+
+use crate::enums::Target;
+use crate::props::{Computed, ComputedBody, FuncProps};
+
+/// A getter returning `ty`, evaluated from `expr` or by calling `func(&self)`.
+pub(crate) fn expand_computed(computed: &Computed, func_props: &FuncProps) -> Result<TokenStream> {
+    let FuncProps {
+        name,
+        target,
+        inline,
+        ..
+    } = func_props;
+
+    if let Target::SharedTrait(_) = target {
+        return Err(Error::new_spanned(
+            &computed.name,
+            "`shared_trait` is not supported on `computed`",
+        ));
+    }
+
+    let ty = &computed.ty;
+    let body = match &computed.body {
+        ComputedBody::Expr(expr) => quote! { #expr },
+        ComputedBody::Func(func) => quote! { #func(self) },
+    };
+    let doc = computed.doc.as_ref().map(|doc| quote! { #[doc = #doc] });
+
+    let vis = func_props.item_vis();
+
+    Ok(quote! {
+        #doc
+        #inline
+        #vis fn #name(&self) -> #ty {
+            #body
+        }
+    })
+}
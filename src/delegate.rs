@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, FnArg, Ident, Pat, Result};
+
+use crate::hooks::WriteHooks;
+use crate::props::Delegate;
+
+/// Forwards the call to the field's method of the same name.
+pub(crate) fn expand_delegate(
+    index: usize,
+    field_ident: &Ident,
+    delegate: &Delegate,
+    hooks: &WriteHooks,
+) -> Result<TokenStream> {
+    let Delegate { vis, inline, sig } = delegate;
+    let name = &sig.ident;
+
+    let Some(receiver) = sig.receiver() else {
+        return Err(Error::new_spanned(
+            sig,
+            "Delegated methods must take `self`",
+        ));
+    };
+
+    let args = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pat_type) => Some(pat_type),
+        })
+        .map(|pat_type| match &*pat_type.pat {
+            Pat::Ident(pat_ident) => Ok(&pat_ident.ident),
+            pat => Err(Error::new_spanned(
+                pat,
+                "Delegated methods must name their arguments, e.g. `fn push(&mut self, value: T)`",
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let dot_await = sig.asyncness.map(|_| quote! { .await });
+    let call = quote! { self.#field_ident.#name(#(#args),*) #dot_await };
+
+    // Methods taking `&mut self` may write to the field.
+    let after_write = match (&receiver.reference, &receiver.mutability) {
+        (Some(_), Some(_)) => hooks.after_write(index, field_ident),
+        _ => quote! {},
+    };
+
+    let body = if after_write.is_empty() {
+        call
+    } else {
+        quote! {
+            let ret = #call;
+            #after_write
+            ret
+        }
+    };
+
+    Ok(quote! {
+        #inline
+        #vis #sig {
+            #body
+        }
+    })
+}
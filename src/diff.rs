@@ -0,0 +1,147 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, GenericParam, Ident, ItemStruct, Lifetime};
+
+use crate::case::{field_name, to_camel_case};
+use crate::field_enum::field_enum_ident;
+use crate::props::FieldProps;
+
+/// `ExampleChange` with a variant per field with a getter as visible as the struct, and `diff`
+/// comparing two instances.
+pub(crate) fn expand_diff(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    field_enum: bool,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let change_ident = format_ident!("{}Change", struct_ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let lifetime: Lifetime = parse_quote! { '__a };
+    let mut change_generics = input.generics.clone();
+    change_generics
+        .params
+        .insert(0, GenericParam::Lifetime(parse_quote! { #lifetime }));
+    let (change_impl_generics, change_ty_generics, _) = change_generics.split_for_impl();
+
+    let fields = field_map
+        .iter()
+        .filter(|(_, field_props)| field_props.is_listed() && field_props.has_getter_at(vis))
+        .collect::<Vec<_>>();
+
+    let variants = fields
+        .iter()
+        .map(|(field_ident, _)| to_camel_case(field_ident))
+        .collect::<Vec<_>>();
+    let names = fields
+        .iter()
+        .map(|(field_ident, _)| field_name(field_ident))
+        .collect::<Vec<_>>();
+    let field_idents = fields.iter().map(|(field_ident, _)| field_ident);
+    let tys = fields
+        .iter()
+        .map(|(_, field_props)| &field_props.ty)
+        .collect::<Vec<_>>();
+
+    let (phantom, phantom_arm) = phantom_variant(input, Some(&lifetime), !fields.is_empty());
+
+    let field_fn = field_enum.then(|| {
+        let enum_ident = field_enum_ident(struct_ident);
+
+        quote! {
+            #vis fn field(&self) -> #enum_ident {
+                match self {
+                    #(Self::#variants { .. } => #enum_ident::#variants,)*
+                    #phantom_arm
+                }
+            }
+        }
+    });
+
+    let change_doc = format!(
+        "A field that differs between two [`{struct_ident}`]s, see [`{struct_ident}::diff`]."
+    );
+
+    quote! {
+        #[doc = #change_doc]
+        #[derive(Clone, Copy, PartialEq)]
+        #vis enum #change_ident #change_generics #where_clause {
+            #(#variants { old: &#lifetime #tys, new: &#lifetime #tys },)*
+            #phantom
+        }
+
+        // Only names the variant, so the field types need not implement `Debug`.
+        impl #change_impl_generics ::core::fmt::Debug for #change_ident #change_ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(Self::#variants { .. } => f.debug_struct(::core::stringify!(#variants)).finish_non_exhaustive(),)*
+                    #phantom_arm
+                }
+            }
+        }
+
+        impl #change_impl_generics #change_ident #change_ty_generics #where_clause {
+            #vis fn field_name(&self) -> &'static str {
+                match self {
+                    #(Self::#variants { .. } => #names,)*
+                    #phantom_arm
+                }
+            }
+
+            #field_fn
+        }
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Every field with a getter whose value in `other` differs from `self`,
+            /// `old` borrows from `self` and `new` from `other`.
+            #vis fn diff<#lifetime>(&#lifetime self, other: &#lifetime Self) -> ::std::vec::Vec<#change_ident #change_ty_generics>
+            where
+                #(#tys: ::core::cmp::PartialEq,)*
+            {
+                let mut changes = ::std::vec::Vec::new();
+
+                #(
+                    if self.#field_idents != other.#field_idents {
+                        changes.push(#change_ident::#variants {
+                            old: &self.#field_idents,
+                            new: &other.#field_idents,
+                        });
+                    }
+                )*
+
+                changes
+            }
+        }
+    }
+}
+
+/// A variant that can never be constructed, keeping the struct's generics (and the `lifetime`
+/// of an enum borrowing from the struct) used, along with the match arm for it.
+pub(crate) fn phantom_variant(
+    input: &ItemStruct,
+    lifetime: Option<&Lifetime>,
+    has_variants: bool,
+) -> (Option<TokenStream>, Option<TokenStream>) {
+    if input.generics.params.is_empty() && has_variants {
+        return (None, None);
+    }
+
+    let struct_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let phantom = match lifetime {
+        Some(lifetime) => quote! { &#lifetime #struct_ident #ty_generics },
+        None => quote! { fn() -> #struct_ident #ty_generics },
+    };
+
+    (
+        Some(quote! {
+            #[doc(hidden)]
+            __Phantom(
+                ::core::convert::Infallible,
+                ::core::marker::PhantomData<#phantom>,
+            ),
+        }),
+        Some(quote! { Self::__Phantom(never, _) => match *never {}, }),
+    )
+}
@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ItemStruct};
+
+use crate::case::field_name;
+use crate::neutral::neutral_newtype;
+use crate::props::FieldProps;
+
+fn dirty_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}Dirty", struct_ident)
+}
+
+/// Every field has a bit, in declaration order.
+pub(crate) fn dirty_words(field_count: usize) -> usize {
+    field_count.div_ceil(64).max(1)
+}
+
+/// `__dirty: ExampleDirty`, the bitset the generated setters mark.
+pub(crate) fn dirty_field(input: &ItemStruct) -> TokenStream {
+    let vis = &input.vis;
+    let dirty_ident = dirty_ident(&input.ident);
+
+    quote! {
+        #[doc(hidden)]
+        #vis __dirty: #dirty_ident
+    }
+}
+
+pub(crate) fn mark_dirty(index: usize) -> TokenStream {
+    let word = index / 64;
+    let bit = 1u64 << (index % 64);
+
+    quote! { self.__dirty.0[#word] |= #bit; }
+}
+
+/// The bitset type, `is_x_dirty()` per field, as every field has a bit, `dirty_fields()` and
+/// `clear_dirty()`.
+pub(crate) fn expand_dirty(input: &ItemStruct, field_map: &[(Ident, FieldProps)]) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let words = dirty_words(field_map.len());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (is_dirty_fns, (names, indices)): (Vec<_>, (Vec<_>, Vec<_>)) = field_map
+        .iter()
+        .enumerate()
+        .map(|(index, (field_ident, _))| {
+            let is_dirty = format_ident!("is_{}_dirty", field_ident);
+            let word = index / 64;
+            let bit = 1u64 << (index % 64);
+
+            let is_dirty_fn = quote! {
+                #vis fn #is_dirty(&self) -> bool {
+                    self.__dirty.0[#word] & #bit != 0
+                }
+            };
+
+            (is_dirty_fn, (field_name(field_ident), index))
+        })
+        .unzip();
+
+    let dirty_doc = format!(
+        "The fields of a [`{struct_ident}`] written so far, which never affect comparisons."
+    );
+    let dirty_type = neutral_newtype(
+        vis,
+        &dirty_ident(struct_ident),
+        quote! { [u64; #words] },
+        quote! { [0; #words] },
+        &dirty_doc,
+    );
+
+    quote! {
+        #dirty_type
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #(#is_dirty_fns)*
+
+            #vis fn is_dirty(&self) -> bool {
+                self.__dirty.0.iter().any(|word| *word != 0)
+            }
+
+            /// The names of the fields written since the last `clear_dirty`, in declaration order.
+            #vis fn dirty_fields(&self) -> impl ::core::iter::Iterator<Item = &'static str> + '_ {
+                const FIELDS: &[(&str, usize)] = &[#((#names, #indices),)*];
+
+                FIELDS
+                    .iter()
+                    .filter(move |(_, index)| self.__dirty.0[index / 64] & (1 << (index % 64)) != 0)
+                    .map(|(name, _)| *name)
+            }
+
+            #vis fn clear_dirty(&mut self) {
+                self.__dirty.0 = [0; #words];
+            }
+        }
+    }
+}
@@ -1,24 +1,21 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use syn::{Error, Meta};
+use syn::{Error, Meta, Path};
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+use crate::case::to_camel_case;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
 pub(crate) enum Inline {
+    #[default]
     None,
     Never,
     Always,
     Sometimes,
 }
 
-impl Default for Inline {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
-impl Into<TokenStream> for &Inline {
-    fn into(self) -> TokenStream {
-        match self {
+impl From<&Inline> for TokenStream {
+    fn from(inline: &Inline) -> Self {
+        match inline {
             Inline::None => quote! {},
             Inline::Never => quote! { #[inline(never)] },
             Inline::Always => quote! { #[inline(always)] },
@@ -27,15 +24,15 @@ impl Into<TokenStream> for &Inline {
     }
 }
 
-impl Into<TokenStream> for Inline {
-    fn into(self) -> TokenStream {
-        (&self).into()
+impl From<Inline> for TokenStream {
+    fn from(inline: Inline) -> Self {
+        (&inline).into()
     }
 }
 
 impl ToTokens for Inline {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(<&Self as Into<TokenStream>>::into(self));
+        tokens.extend(TokenStream::from(self));
     }
 }
 
@@ -44,20 +41,63 @@ pub(crate) enum Kind {
     Setr,
     GetrRef,
     GetrCopy,
+    Lens,
+    Option(OptionFn),
+    Collection(CollectionFn, Container),
+    Map(MapFn, MapContainer),
+    Indexed(IndexedFn),
+    Numeric(NumericFn, NumericMode),
+    Bool(BoolFn),
 }
 
-impl Into<&'static str> for Kind {
-    fn into(self) -> &'static str {
-        match self {
+impl From<Kind> for &'static str {
+    fn from(kind: Kind) -> Self {
+        match kind {
             Kind::Setr => "set",
             Kind::GetrRef | Kind::GetrCopy => "get",
+            Kind::Lens => "lens",
+            Kind::Option(_) => "option",
+            Kind::Collection(..) => "collection",
+            Kind::Map(..) => "map",
+            Kind::Indexed(_) => "indexed",
+            Kind::Numeric(..) => "numeric",
+            Kind::Bool(_) => "bool",
         }
     }
 }
 
 impl Kind {
     pub(crate) fn into_ident(self, field: &Ident) -> Ident {
-        format_ident!("{}_{}", <Self as Into<&'static str>>::into(self), field)
+        match self {
+            Kind::Lens => format_ident!("{}_{}", field, <&'static str>::from(self)),
+            Kind::Option(option_fn) => {
+                let (prefix, suffix) = option_fn.affixes();
+                format_ident!("{}{}{}", prefix, field, suffix)
+            }
+            Kind::Collection(collection_fn, container) => {
+                let (prefix, suffix) = collection_fn.affixes(container);
+                format_ident!("{}{}{}", prefix, field, suffix)
+            }
+            Kind::Map(map_fn, _) => {
+                let (prefix, suffix) = map_fn.affixes();
+                format_ident!("{}{}{}", prefix, field, suffix)
+            }
+            Kind::Indexed(indexed_fn) => {
+                let (prefix, suffix) = indexed_fn.affixes();
+                format_ident!("{}{}{}", prefix, field, suffix)
+            }
+            Kind::Numeric(numeric_fn, _) => format_ident!("{}_{}", numeric_fn.prefix(), field),
+            Kind::Bool(bool_fn) => format_ident!("{}_{}", bool_fn.prefix(), field),
+            _ => format_ident!("{}_{}", <&'static str>::from(self), field),
+        }
+    }
+
+    /// The trait declared by `shared_traits!` for this kind of accessor, `HasField` or `HasFieldMut`.
+    pub(crate) fn shared_trait_ident(self, field: &Ident) -> Ident {
+        match self {
+            Kind::Setr => format_ident!("Has{}Mut", to_camel_case(field)),
+            _ => format_ident!("Has{}", to_camel_case(field)),
+        }
     }
 }
 
@@ -70,10 +110,11 @@ impl TryFrom<&Meta> for Kind {
             _ if gsflag.path().is_ident("set") => Ok(Kind::Setr),
             _ if gsflag.path().is_ident("get") => Ok(Kind::GetrRef),
             _ if gsflag.path().is_ident("get_copy") => Ok(Kind::GetrCopy),
+            _ if gsflag.path().is_ident("lens") => Ok(Kind::Lens),
 
             _ => Err(Error::new_spanned(
                 gsflag,
-                "Valid values are `skip`, `get`, `get_copy`, and `set`",
+                "Valid values are `skip`, `get`, `get_copy`, `set`, `lens`, `option`, `collection`, `map`, `indexed`, `numeric` and `bool`",
             )),
         }
     }
@@ -86,3 +127,341 @@ impl TryFrom<Meta> for Kind {
         (&gsflag).try_into()
     }
 }
+
+/// The functions of the `option` preset, for `Option<T>` fields.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum OptionFn {
+    /// `x(&self) -> Option<&T>`
+    Get,
+    /// `x_mut(&mut self) -> Option<&mut T>`
+    GetMut,
+    /// `set_x(&mut self, T)`, wrapping it in `Some`
+    Set,
+    /// `clear_x(&mut self) -> Option<T>`
+    Clear,
+    /// `has_x(&self) -> bool`
+    Has,
+    /// `x_or_insert_with(&mut self, impl FnOnce() -> T) -> &mut T`
+    GetOrInsertWith,
+}
+
+impl OptionFn {
+    pub(crate) const ALL: [OptionFn; 6] = [
+        OptionFn::Get,
+        OptionFn::GetMut,
+        OptionFn::Set,
+        OptionFn::Clear,
+        OptionFn::Has,
+        OptionFn::GetOrInsertWith,
+    ];
+
+    /// What goes before and after the field name in the function name.
+    fn affixes(self) -> (&'static str, &'static str) {
+        match self {
+            OptionFn::Get => ("", ""),
+            OptionFn::GetMut => ("", "_mut"),
+            OptionFn::Set => ("set_", ""),
+            OptionFn::Clear => ("clear_", ""),
+            OptionFn::Has => ("has_", ""),
+            OptionFn::GetOrInsertWith => ("", "_or_insert_with"),
+        }
+    }
+}
+
+/// The collections supported by the `collection` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Container {
+    Vec,
+    VecDeque,
+    HashSet,
+    BTreeSet,
+}
+
+impl Container {
+    pub(crate) const NAMES: [&'static str; 4] = ["Vec", "VecDeque", "HashSet", "BTreeSet"];
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Vec" => Some(Container::Vec),
+            "VecDeque" => Some(Container::VecDeque),
+            "HashSet" => Some(Container::HashSet),
+            "BTreeSet" => Some(Container::BTreeSet),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_set(self) -> bool {
+        matches!(self, Container::HashSet | Container::BTreeSet)
+    }
+}
+
+/// The functions of the `collection` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum CollectionFn {
+    /// `push_x(&mut self, T)`, `insert_x(&mut self, T) -> bool` for sets
+    Push,
+    /// `extend_x(&mut self, impl IntoIterator<Item = T>)`
+    Extend,
+    /// `remove_x(&mut self, usize) -> Option<T>`, `remove_x(&mut self, &Q) -> bool` for sets
+    Remove,
+    /// `clear_x(&mut self)`
+    Clear,
+    /// `x_len(&self) -> usize`
+    Len,
+    /// `x_is_empty(&self) -> bool`
+    IsEmpty,
+    /// `iter_x(&self) -> impl Iterator<Item = &T>`
+    Iter,
+    /// `contains_x(&self, &Q) -> bool`, for anything the items compare with (borrow as, for sets)
+    Contains,
+}
+
+impl CollectionFn {
+    pub(crate) const ALL: [CollectionFn; 8] = [
+        CollectionFn::Push,
+        CollectionFn::Extend,
+        CollectionFn::Remove,
+        CollectionFn::Clear,
+        CollectionFn::Len,
+        CollectionFn::IsEmpty,
+        CollectionFn::Iter,
+        CollectionFn::Contains,
+    ];
+
+    fn affixes(self, container: Container) -> (&'static str, &'static str) {
+        match self {
+            CollectionFn::Push if container.is_set() => ("insert_", ""),
+            CollectionFn::Push => ("push_", ""),
+            CollectionFn::Extend => ("extend_", ""),
+            CollectionFn::Remove => ("remove_", ""),
+            CollectionFn::Clear => ("clear_", ""),
+            CollectionFn::Len => ("", "_len"),
+            CollectionFn::IsEmpty => ("", "_is_empty"),
+            CollectionFn::Iter => ("iter_", ""),
+            CollectionFn::Contains => ("contains_", ""),
+        }
+    }
+}
+
+/// The maps supported by the `map` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum MapContainer {
+    HashMap,
+    BTreeMap,
+}
+
+impl MapContainer {
+    pub(crate) const NAMES: [&'static str; 2] = ["HashMap", "BTreeMap"];
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "HashMap" => Some(MapContainer::HashMap),
+            "BTreeMap" => Some(MapContainer::BTreeMap),
+            _ => None,
+        }
+    }
+}
+
+/// The functions of the `map` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum MapFn {
+    /// `get_x(&self, &Q) -> Option<&V>`
+    Get,
+    /// `get_x_mut(&mut self, &Q) -> Option<&mut V>`
+    GetMut,
+    /// `insert_x(&mut self, K, V) -> Option<V>`
+    Insert,
+    /// `remove_x(&mut self, &Q) -> Option<V>`
+    Remove,
+    /// `x_entry(&mut self, K) -> Entry<'_, K, V>`
+    Entry,
+    /// `x_keys(&self) -> impl Iterator<Item = &K>`
+    Keys,
+    /// `x_values(&self) -> impl Iterator<Item = &V>`
+    Values,
+    /// `x_len(&self) -> usize`
+    Len,
+}
+
+impl MapFn {
+    pub(crate) const ALL: [MapFn; 8] = [
+        MapFn::Get,
+        MapFn::GetMut,
+        MapFn::Insert,
+        MapFn::Remove,
+        MapFn::Entry,
+        MapFn::Keys,
+        MapFn::Values,
+        MapFn::Len,
+    ];
+
+    fn affixes(self) -> (&'static str, &'static str) {
+        match self {
+            MapFn::Get => ("get_", ""),
+            MapFn::GetMut => ("get_", "_mut"),
+            MapFn::Insert => ("insert_", ""),
+            MapFn::Remove => ("remove_", ""),
+            MapFn::Entry => ("", "_entry"),
+            MapFn::Keys => ("", "_keys"),
+            MapFn::Values => ("", "_values"),
+            MapFn::Len => ("", "_len"),
+        }
+    }
+}
+
+/// The functions of the `indexed` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum IndexedFn {
+    /// `x_at(&self, usize) -> Option<&T>`
+    At,
+    /// `x_at_mut(&mut self, usize) -> Option<&mut T>`
+    AtMut,
+    /// `set_x_at(&mut self, usize, T) -> Result<T, ExampleIndexError>`
+    SetAt,
+    /// `x_slice(&self) -> &[T]`
+    Slice,
+    /// `x_get::<I>(&self) -> &T`, checked at compile time, arrays only
+    Get,
+}
+
+impl IndexedFn {
+    pub(crate) const ALL: [IndexedFn; 5] = [
+        IndexedFn::At,
+        IndexedFn::AtMut,
+        IndexedFn::SetAt,
+        IndexedFn::Slice,
+        IndexedFn::Get,
+    ];
+
+    fn affixes(self) -> (&'static str, &'static str) {
+        match self {
+            IndexedFn::At => ("", "_at"),
+            IndexedFn::AtMut => ("", "_at_mut"),
+            IndexedFn::SetAt => ("set_", "_at"),
+            IndexedFn::Slice => ("", "_slice"),
+            IndexedFn::Get => ("", "_get"),
+        }
+    }
+}
+
+/// How the `numeric` preset handles overflow.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub(crate) enum NumericMode {
+    /// The arithmetic operators, which panic on overflow in debug builds.
+    #[default]
+    Plain,
+    Wrapping,
+    Saturating,
+    /// Leaves the field unchanged on overflow, and returns `false`.
+    Checked,
+}
+
+impl NumericMode {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "wrapping" => Some(NumericMode::Wrapping),
+            "saturating" => Some(NumericMode::Saturating),
+            "checked" => Some(NumericMode::Checked),
+            _ => None,
+        }
+    }
+}
+
+/// The functions of the `numeric` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum NumericFn {
+    /// `incr_x(&mut self)`
+    Incr,
+    /// `decr_x(&mut self)`
+    Decr,
+    /// `add_x(&mut self, T)`
+    Add,
+    /// `sub_x(&mut self, T)`
+    Sub,
+}
+
+impl NumericFn {
+    pub(crate) const ALL: [NumericFn; 4] = [
+        NumericFn::Incr,
+        NumericFn::Decr,
+        NumericFn::Add,
+        NumericFn::Sub,
+    ];
+
+    fn prefix(self) -> &'static str {
+        match self {
+            NumericFn::Incr => "incr",
+            NumericFn::Decr => "decr",
+            NumericFn::Add => "add",
+            NumericFn::Sub => "sub",
+        }
+    }
+}
+
+/// The functions of the `bool` preset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum BoolFn {
+    /// `is_x(&self) -> bool`
+    Is,
+    /// `toggle_x(&mut self)`
+    Toggle,
+    /// `enable_x(&mut self)`
+    Enable,
+    /// `disable_x(&mut self)`
+    Disable,
+}
+
+impl BoolFn {
+    pub(crate) const ALL: [BoolFn; 4] =
+        [BoolFn::Is, BoolFn::Toggle, BoolFn::Enable, BoolFn::Disable];
+
+    fn prefix(self) -> &'static str {
+        match self {
+            BoolFn::Is => "is",
+            BoolFn::Toggle => "toggle",
+            BoolFn::Enable => "enable",
+            BoolFn::Disable => "disable",
+        }
+    }
+}
+
+/// The `impl` block a generated function is emitted into.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Target {
+    /// `impl Example { .. }`
+    Inherent,
+    /// `impl <impl_trait> for Example { .. }`, see `#[get_set(impl_trait = "..")]`.
+    ImplTrait,
+    /// `impl HasField for Example { .. }`, see `shared_traits!`.
+    SharedTrait(Path),
+}
+
+/// A trait implemented for the struct by forwarding to a field, e.g. `#[gsflags(as_ref)]`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Projection {
+    AsRef,
+    AsMut,
+    Borrow,
+    Deref,
+    // Also implements `Deref`.
+    DerefMut,
+    Index,
+}
+
+impl Projection {
+    pub(crate) fn from_flag(gsflag: &Meta) -> Option<Self> {
+        let Meta::Path(path) = gsflag else {
+            return None;
+        };
+
+        match path.get_ident()?.to_string().as_str() {
+            "as_ref" => Some(Projection::AsRef),
+            "as_mut" => Some(Projection::AsMut),
+            "borrow" => Some(Projection::Borrow),
+            "deref" => Some(Projection::Deref),
+            "deref_mut" => Some(Projection::DerefMut),
+            "index" => Some(Projection::Index),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ItemStruct, Path};
+
+use crate::case::to_camel_case;
+use crate::diff::phantom_variant;
+use crate::props::{Events, FieldProps};
+
+pub(crate) fn event_ident(struct_ident: &Ident, events: &Events) -> Ident {
+    events
+        .name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}Event", struct_ident))
+}
+
+fn variant_ident(field_ident: &Ident) -> Ident {
+    format_ident!("{}Set", to_camel_case(field_ident))
+}
+
+/// Hands the event for a setter's write to the sink, with a clone of the new value.
+/// Fields without a variant, whose only setter converts its argument, emit nothing.
+pub(crate) fn emit(
+    struct_ident: &Ident,
+    events: &Events,
+    field_ident: &Ident,
+    field_props: &FieldProps,
+) -> Option<TokenStream> {
+    let sink = events.sink.as_ref()?;
+    if field_props.setter().is_none() && field_props.option_setter().is_none() {
+        return None;
+    }
+
+    let event_ident = event_ident(struct_ident, events);
+    let variant = variant_ident(field_ident);
+
+    Some(quote! {
+        #sink(self, #event_ident::#variant(::core::clone::Clone::clone(&self.#field_ident)));
+    })
+}
+
+/// The event enum with a `NameSet(..)` variant per field with a setter taking the field's type, and
+/// `apply_event`. Only `Clone` is derived, so the field types need not implement anything else.
+pub(crate) fn expand_events(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    events: &Events,
+    impl_trait: Option<&Path>,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let event_ident = event_ident(struct_ident, events);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut variants = quote! {};
+    let mut apply_arms = quote! {};
+
+    for (field_ident, field_props) in field_map {
+        let variant = variant_ident(field_ident);
+
+        // The `option` preset's `set_x` takes the inner value, `None` is replayed by `clear_x`.
+        let apply = match (field_props.setter(), field_props.option_setter()) {
+            (Some(setter), _) => {
+                let setter = setter.call_path(&quote! { Self }, impl_trait);
+
+                quote! {
+                    let _ = #setter(self, value);
+                }
+            }
+            (None, Some(setter)) => {
+                let setter = setter.call_path(&quote! { Self }, impl_trait);
+                // Both are generated by the preset.
+                let clearer = field_props
+                    .option_clearer()
+                    .unwrap()
+                    .call_path(&quote! { Self }, impl_trait);
+
+                quote! {
+                    match value {
+                        ::core::option::Option::Some(value) => {
+                            let _ = #setter(self, value);
+                        }
+                        ::core::option::Option::None => {
+                            #clearer(self);
+                        }
+                    }
+                }
+            }
+            (None, None) => continue,
+        };
+
+        let ty = &field_props.ty;
+
+        variants.extend(quote! { #variant(#ty), });
+        apply_arms.extend(quote! {
+            #event_ident::#variant(value) => {
+                #apply
+            }
+        });
+    }
+
+    let (phantom, _) = phantom_variant(input, None, !variants.is_empty());
+    let phantom_arm = phantom
+        .as_ref()
+        .map(|_| quote! { #event_ident::__Phantom(never, _) => match never {}, });
+
+    let event_doc = format!("A write to a field of [`{struct_ident}`] by its setter.");
+
+    quote! {
+        #[doc = #event_doc]
+        #[derive(Clone)]
+        #vis enum #event_ident #generics #where_clause {
+            #variants
+            #phantom
+        }
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Replays `event` through the generated setter of its field.
+            ///
+            /// The setter does everything it always does, so a sink receives the event again.
+            #vis fn apply_event(&mut self, event: #event_ident #ty_generics) {
+                match event {
+                    #apply_arms
+                    #phantom_arm
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ItemStruct};
+
+use crate::case::{field_name, to_camel_case};
+use crate::props::FieldProps;
+
+pub(crate) fn field_enum_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}Field", struct_ident)
+}
+
+/// `ExampleField`, its `FromStr`/`Display` impls, and the field name constants of `Example`.
+pub(crate) fn expand_field_enum(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let enum_ident = field_enum_ident(struct_ident);
+    let error_ident = format_ident!("Parse{}Error", enum_ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = field_map
+        .iter()
+        .filter(|(_, field_props)| field_props.is_listed())
+        .map(|(field_ident, _)| field_ident);
+
+    let variants = fields.clone().map(to_camel_case).collect::<Vec<_>>();
+    let names = fields.clone().map(field_name).collect::<Vec<_>>();
+    let name_consts =
+        fields.map(|field_ident| format_ident!("{}_FIELD", field_name(field_ident).to_uppercase()));
+
+    let enum_doc = format!("The fields of [`{struct_ident}`].");
+    let error_doc = format!("The error returned when parsing an unknown [`{enum_ident}`].");
+
+    quote! {
+        #[doc = #enum_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #vis enum #enum_ident {
+            #(#variants,)*
+        }
+
+        impl #enum_ident {
+            #vis const ALL: &'static [Self] = &[#(Self::#variants,)*];
+
+            #vis fn field_name(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #names,)*
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #enum_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.field_name())
+            }
+        }
+
+        impl ::core::str::FromStr for #enum_ident {
+            type Err = #error_ident;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    #(#names => ::core::result::Result::Ok(Self::#variants),)*
+                    _ => ::core::result::Result::Err(#error_ident(s.into())),
+                }
+            }
+        }
+
+        #[doc = #error_doc]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        #vis struct #error_ident(#vis ::std::string::String);
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "unknown field `{}`", self.0)
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #vis const FIELD_NAMES: &'static [&'static str] = &[#(#names,)*];
+
+            #(#vis const #name_consts: &'static str = #names;)*
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ItemStruct, Path, Visibility};
+
+use crate::case::{field_name, to_camel_case, to_snake_case};
+use crate::props::FieldProps;
+
+/// The traits implemented by the field keys of `#[get_set(field_keys)]`.
+pub(crate) fn expand_field_traits(vis: Visibility) -> TokenStream {
+    quote! {
+        #vis trait Field<S: ?Sized> {
+            type Type;
+
+            const NAME: &'static str;
+        }
+
+        #vis trait GetField<S: ?Sized>: Field<S> {
+            fn get(s: &S) -> &Self::Type;
+        }
+
+        #vis trait SetField<S: ?Sized>: Field<S> {
+            fn set(s: &mut S, value: Self::Type);
+        }
+    }
+}
+
+/// A zero-sized key per field in `mod example_fields`, plus `get::<F>()` and `set::<F>(..)`.
+pub(crate) fn expand_field_keys(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    impl_trait: Option<&Path>,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let mod_ident = format_ident!("{}_fields", to_snake_case(struct_ident));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let self_ty = quote! { #struct_ident #ty_generics };
+
+    let mut keys = quote! {};
+    let mut impls = quote! {};
+
+    for (field_ident, field_props) in field_map {
+        // Never more than the accessors themselves expose.
+        let setter = field_props.setter_at(vis);
+        let has_getter = field_props.has_getter_at(vis);
+
+        if !has_getter && setter.is_none() {
+            continue;
+        }
+
+        let ty = &field_props.ty;
+        let key = to_camel_case(field_ident);
+        let name = field_name(field_ident);
+
+        keys.extend(quote! {
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            pub struct #key;
+        });
+
+        impls.extend(quote! {
+            impl #impl_generics Field<#struct_ident #ty_generics> for #mod_ident::#key #where_clause {
+                type Type = #ty;
+
+                const NAME: &'static str = #name;
+            }
+        });
+
+        if has_getter {
+            impls.extend(quote! {
+                impl #impl_generics GetField<#struct_ident #ty_generics> for #mod_ident::#key #where_clause {
+                    #[inline]
+                    fn get(s: &#struct_ident #ty_generics) -> &#ty {
+                        &s.#field_ident
+                    }
+                }
+            });
+        }
+
+        if let Some(setter) = setter {
+            // Goes through the generated setter, so anything it does besides assigning still happens.
+            // A veto by its `before` hook leaves the field as it was.
+            let setter = setter.call_path(&self_ty, impl_trait);
+
+            impls.extend(quote! {
+                impl #impl_generics SetField<#struct_ident #ty_generics> for #mod_ident::#key #where_clause {
+                    #[inline]
+                    fn set(s: &mut #struct_ident #ty_generics, value: #ty) {
+                        let _ = #setter(s, value);
+                    }
+                }
+            });
+        }
+    }
+
+    quote! {
+        #vis mod #mod_ident {
+            #keys
+        }
+
+        #impls
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #[inline]
+            #vis fn get<F: GetField<Self>>(&self) -> &F::Type {
+                F::get(self)
+            }
+
+            #[inline]
+            #vis fn set<F: SetField<Self>>(&mut self, value: F::Type) {
+                F::set(self, value)
+            }
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    braced, bracketed,
+    parse::{Parse, ParseStream},
+    Error, FnArg, Ident, ItemStruct, LitStr, Pat, PathArguments, Result, Signature, Token, Type,
+    Visibility,
+};
+
+use crate::case::field_name;
+use crate::enums::{Kind, Target};
+use crate::hooks::WriteHooks;
+use crate::props::{FieldProps, Flatten};
+
+/// The `macro_rules!` a `#[get_set(flattenable)]` struct hands its accessors to.
+/// It is re-exported under the struct's name, in the macro namespace, so it is
+/// reachable through the same paths and imports as the struct.
+fn flatten_macro_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("__get_set_flatten_{}", struct_ident)
+}
+
+/// A callback macro listing the struct's inherent getters and setters,
+/// which `#[gsflags(flatten)]` fields of other structs invoke to forward them.
+pub(crate) fn expand_flattenable(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`flattenable` does not support generic structs",
+        ));
+    }
+
+    let struct_ident = &input.ident;
+    let macro_ident = flatten_macro_ident(struct_ident);
+    let mut accessors = quote! {};
+
+    for (field_ident, field_props) in field_map {
+        let ty = &field_props.ty;
+
+        for func_props in &field_props.props {
+            if func_props.target != Target::Inherent {
+                continue;
+            }
+
+            let name = &func_props.name;
+
+            match func_props.kind {
+                Kind::Setr => {
+                    let new_val = format_ident!("new_{}", field_ident);
+                    let arg_ty = func_props.ty.as_ref().unwrap_or(ty);
+                    let ret = func_props.setter_ret();
+
+                    accessors.extend(quote! {
+                        set #field_ident: fn #name(&mut self, #new_val: #arg_ty) #ret;
+                    });
+                }
+                Kind::GetrRef | Kind::GetrCopy => {
+                    let ret = match &func_props.ty {
+                        Some(ty_override) => quote! { #ty_override },
+                        None if func_props.kind == Kind::GetrRef
+                            && !matches!(ty, Type::Reference(_)) =>
+                        {
+                            quote! { &#ty }
+                        }
+                        None => quote! { #ty },
+                    };
+
+                    accessors.extend(quote! {
+                        get #field_ident: fn #name(&self) -> #ret;
+                    });
+                }
+                // Lenses are typed by the struct they start from.
+                Kind::Lens => {}
+                // Only the plain getters and setters are forwarded.
+                Kind::Option(_)
+                | Kind::Collection(..)
+                | Kind::Map(..)
+                | Kind::Indexed(_)
+                | Kind::Numeric(..)
+                | Kind::Bool(_) => {}
+            }
+        }
+    }
+
+    Ok(quote! {
+        #[doc(hidden)]
+        macro_rules! #macro_ident {
+            ($($outer:tt)*) => {
+                ::get_set_macro::__flatten! { $($outer)* [#accessors] }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #macro_ident as #struct_ident;
+    })
+}
+
+/// Invokes the callback macro of the field's type, next to the type itself.
+pub(crate) fn expand_flatten(
+    input: &ItemStruct,
+    index: usize,
+    field_ident: &Ident,
+    field_props: &FieldProps,
+    flatten: &Flatten,
+    hooks: &WriteHooks,
+) -> Result<TokenStream> {
+    let struct_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The callback macro has the name of the type.
+    let mut path = match &field_props.ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.clone(),
+        ty => {
+            return Err(Error::new_spanned(
+                ty,
+                "`flatten` requires the field's type to be a `#[get_set(flattenable)]` struct",
+            ))
+        }
+    };
+    path.segments.last_mut().unwrap().arguments = PathArguments::None;
+
+    let prefix = flatten
+        .prefix
+        .clone()
+        .unwrap_or_else(|| field_name(field_ident));
+    let vis = flatten
+        .opt_func_props
+        .vis
+        .clone()
+        .unwrap_or(Visibility::Inherited);
+    let inline = flatten.opt_func_props.inline.unwrap_or_default();
+    let after_write = hooks.after_write(index, field_ident);
+
+    Ok(quote! {
+        #path! {
+            { impl #impl_generics #struct_ident #ty_generics #where_clause }
+            self #field_ident #prefix { #vis } { #inline } { #after_write }
+        }
+    })
+}
+
+struct FlattenAccessor {
+    kind: Ident,
+    field: Ident,
+    sig: Signature,
+}
+
+impl Parse for FlattenAccessor {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let kind = input.parse()?;
+        let field = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let sig = input.parse()?;
+        input.parse::<Token![;]>()?;
+
+        Ok(Self { kind, field, sig })
+    }
+}
+
+/// The input of `__flatten!`, the outer struct's part followed by the inner struct's accessors.
+pub(crate) struct FlattenInput {
+    impl_header: TokenStream,
+    // The outer struct's `self`, which its `after_write` refers to.
+    self_token: Token![self],
+    field: Ident,
+    prefix: LitStr,
+    vis: Visibility,
+    inline: TokenStream,
+    after_write: TokenStream,
+    accessors: Vec<FlattenAccessor>,
+}
+
+impl Parse for FlattenInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let impl_header;
+        braced!(impl_header in input);
+        let self_token = input.parse()?;
+        let field = input.parse()?;
+        let prefix = input.parse()?;
+        let vis;
+        braced!(vis in input);
+        let inline;
+        braced!(inline in input);
+        let after_write;
+        braced!(after_write in input);
+        let accessors;
+        bracketed!(accessors in input);
+
+        let mut parsed = Vec::new();
+        while !accessors.is_empty() {
+            parsed.push(accessors.parse()?);
+        }
+
+        Ok(Self {
+            impl_header: impl_header.parse()?,
+            self_token,
+            field,
+            prefix,
+            vis: vis.parse()?,
+            inline: inline.parse()?,
+            after_write: after_write.parse()?,
+            accessors: parsed,
+        })
+    }
+}
+
+/// The forwarding accessors, `get_inner_timeout` for `get_timeout` with the prefix `inner`.
+pub(crate) fn expand_flatten_accessors(input: FlattenInput) -> TokenStream {
+    let FlattenInput {
+        impl_header,
+        self_token,
+        field,
+        prefix,
+        vis,
+        inline,
+        after_write,
+        accessors,
+    } = input;
+    let prefix = prefix.value();
+
+    let mut funcs = quote! {};
+
+    for FlattenAccessor {
+        kind,
+        field: inner_field,
+        mut sig,
+    } in accessors
+    {
+        let name = sig.ident.clone();
+
+        // The receiver written by the inner struct has a different hygiene context.
+        if let Some(FnArg::Receiver(receiver)) = sig.inputs.first_mut() {
+            receiver.self_token = self_token;
+        }
+
+        // Default names get the prefix after the kind, custom names in front.
+        let outer_name = if prefix.is_empty() {
+            name.clone()
+        } else if name == format_ident!("{}_{}", kind, inner_field) {
+            format_ident!("{}_{}_{}", kind, prefix, inner_field)
+        } else {
+            format_ident!("{}_{}", prefix, name)
+        };
+
+        let args = sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => match &*pat_type.pat {
+                    Pat::Ident(pat_ident) => Some(&pat_ident.ident),
+                    _ => None,
+                },
+                FnArg::Receiver(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let call = quote! { #self_token.#field.#name(#(#args),*) };
+        let body = if kind == "set" && !after_write.is_empty() {
+            quote! {
+                let ret = #call;
+                #after_write
+                ret
+            }
+        } else {
+            call
+        };
+
+        sig.ident = outer_name;
+
+        funcs.extend(quote! {
+            #inline
+            #vis #sig {
+                #body
+            }
+        });
+    }
+
+    quote! {
+        #impl_header {
+            #funcs
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ItemStruct};
+
+use crate::case::to_camel_case;
+use crate::diff::phantom_variant;
+use crate::hooks::WriteHooks;
+use crate::observe::notify;
+use crate::props::{FieldProps, History};
+
+fn history_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}History", struct_ident)
+}
+
+fn entry_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}HistoryEntry", struct_ident)
+}
+
+/// `__history: ExampleHistory`, the undo and redo stacks.
+pub(crate) fn history_field(input: &ItemStruct) -> TokenStream {
+    let vis = &input.vis;
+    let history_ident = history_ident(&input.ident);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    quote! {
+        #[doc(hidden)]
+        #vis __history: #history_ident #ty_generics
+    }
+}
+
+/// Pushes the value a setter replaced, `old`, onto the undo stack.
+pub(crate) fn record(struct_ident: &Ident, field_ident: &Ident, old: TokenStream) -> TokenStream {
+    let entry_ident = entry_ident(struct_ident);
+    let variant = to_camel_case(field_ident);
+
+    quote! { self.__history.record(#entry_ident::#variant(#old)); }
+}
+
+/// The history types, and `undo`/`redo` swapping the recorded values back in.
+pub(crate) fn expand_history(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    history: &History,
+    hooks: &WriteHooks,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let history_ident = history_ident(struct_ident);
+    let entry_ident = entry_ident(struct_ident);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields = field_map
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, field_props))| field_props.history)
+        .collect::<Vec<_>>();
+
+    let variants = fields
+        .iter()
+        .map(|(_, (field_ident, _))| to_camel_case(field_ident))
+        .collect::<Vec<_>>();
+    let tys = fields.iter().map(|(_, (_, field_props))| &field_props.ty);
+    let (phantom, _) = phantom_variant(input, None, !fields.is_empty());
+    let phantom_arm = phantom
+        .as_ref()
+        .map(|_| quote! { #entry_ident::__Phantom(never, _) => match never {}, });
+
+    // Swaps the recorded value back in, and records the value it replaced on the `other` stack.
+    let swap_arms = |other: TokenStream| {
+        fields
+            .iter()
+            .zip(&variants)
+            .map(|((index, (field_ident, _)), variant)| {
+                let after_write = hooks.after_write(*index, field_ident);
+                let notify = hooks
+                    .struct_props
+                    .observable
+                    .as_ref()
+                    .map(|observable| notify(struct_ident, observable, field_ident));
+
+                quote! {
+                    #entry_ident::#variant(value) => {
+                        let old = ::core::mem::replace(&mut self.#field_ident, value);
+                        #after_write
+                        #notify
+                        self.__history.#other(#entry_ident::#variant(old));
+                    }
+                }
+            })
+            .collect::<TokenStream>()
+    };
+    let undo_arms = swap_arms(quote! { push_redo });
+    let redo_arms = swap_arms(quote! { push_undo });
+
+    let trim = history.capacity.map(|capacity| {
+        quote! {
+            if self.undo.len() > #capacity {
+                self.undo.pop_front();
+            }
+        }
+    });
+
+    let entry_doc = format!("A value replaced by a setter of [`{struct_ident}`].");
+    let history_doc =
+        format!("The undo and redo stacks of a [`{struct_ident}`]. They never affect comparisons.");
+
+    quote! {
+        #[doc = #entry_doc]
+        #[derive(Clone)]
+        #vis enum #entry_ident #generics #where_clause {
+            #(#variants(#tys),)*
+            #phantom
+        }
+
+        #[doc = #history_doc]
+        #[derive(Clone)]
+        #vis struct #history_ident #generics #where_clause {
+            undo: ::std::collections::VecDeque<#entry_ident #ty_generics>,
+            redo: ::std::vec::Vec<#entry_ident #ty_generics>,
+        }
+
+        impl #impl_generics #history_ident #ty_generics #where_clause {
+            /// A new write, which can no longer be followed by a redo.
+            fn record(&mut self, entry: #entry_ident #ty_generics) {
+                self.redo.clear();
+                self.push_undo(entry);
+            }
+
+            fn push_undo(&mut self, entry: #entry_ident #ty_generics) {
+                self.undo.push_back(entry);
+                #trim
+            }
+
+            fn push_redo(&mut self, entry: #entry_ident #ty_generics) {
+                self.redo.push(entry);
+            }
+        }
+
+        impl #impl_generics ::core::default::Default for #history_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    undo: ::std::collections::VecDeque::new(),
+                    redo: ::std::vec::Vec::new(),
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Debug for #history_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!(#history_ident))
+                    .field("undo", &self.undo.len())
+                    .field("redo", &self.redo.len())
+                    .finish()
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq for #history_ident #ty_generics #where_clause {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #history_ident #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::hash::Hash for #history_ident #ty_generics #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+        }
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Reverts the latest recorded write, returns `false` if there is nothing to undo.
+            #vis fn undo(&mut self) -> bool {
+                match self.__history.undo.pop_back() {
+                    ::core::option::Option::Some(entry) => {
+                        match entry {
+                            #undo_arms
+                            #phantom_arm
+                        }
+                        true
+                    }
+                    ::core::option::Option::None => false,
+                }
+            }
+
+            /// Reapplies the latest undone write, returns `false` if there is nothing to redo.
+            #vis fn redo(&mut self) -> bool {
+                match self.__history.redo.pop() {
+                    ::core::option::Option::Some(entry) => {
+                        match entry {
+                            #redo_arms
+                            #phantom_arm
+                        }
+                        true
+                    }
+                    ::core::option::Option::None => false,
+                }
+            }
+
+            #vis fn can_undo(&self) -> bool {
+                !self.__history.undo.is_empty()
+            }
+
+            #vis fn can_redo(&self) -> bool {
+                !self.__history.redo.is_empty()
+            }
+
+            #vis fn clear_history(&mut self) {
+                self.__history = ::core::default::Default::default();
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+use crate::cache::invalidate;
+use crate::dirty::mark_dirty;
+use crate::events::emit;
+use crate::history::record;
+use crate::observe::notify;
+use crate::props::{FieldProps, FuncProps, StructProps, TrackDirty};
+use crate::version::bump;
+
+/// The bookkeeping that the generated mutators do around writing to a field.
+pub(crate) struct WriteHooks<'a> {
+    pub(crate) struct_ident: &'a Ident,
+    pub(crate) struct_props: &'a StructProps,
+}
+
+impl WriteHooks<'_> {
+    /// The body of a setter writing `new_val` into the field at `index`.
+    pub(crate) fn setter_body(
+        &self,
+        index: usize,
+        field_ident: &Ident,
+        field_props: &FieldProps,
+        new_val: &Ident,
+        func_props: &FuncProps,
+    ) -> TokenStream {
+        self.write_body(index, field_ident, field_props, new_val, func_props, false)
+    }
+
+    /// The body of a setter that evaluates to the value it replaced, like `clear_x`.
+    pub(crate) fn replacer_body(
+        &self,
+        index: usize,
+        field_ident: &Ident,
+        field_props: &FieldProps,
+        new_val: &Ident,
+        func_props: &FuncProps,
+    ) -> TokenStream {
+        self.write_body(index, field_ident, field_props, new_val, func_props, true)
+    }
+
+    fn write_body(
+        &self,
+        index: usize,
+        field_ident: &Ident,
+        field_props: &FieldProps,
+        new_val: &Ident,
+        func_props: &FuncProps,
+        returns_old: bool,
+    ) -> TokenStream {
+        let mut before = quote! {};
+        let mut after = quote! {};
+
+        if let Some(before_hook) = &func_props.before {
+            before.extend(match func_props.error {
+                Some(_) => quote! { #before_hook(self, &self.#field_ident, &#new_val)?; },
+                None => quote! {
+                    if !#before_hook(self, &self.#field_ident, &#new_val) {
+                        return false;
+                    }
+                },
+            });
+        }
+
+        let mut mark = quote! {};
+
+        if self.struct_props.track_dirty.is_some() {
+            mark.extend(mark_dirty(index));
+        }
+
+        if let Some(versioned) = &self.struct_props.versioned {
+            mark.extend(bump(versioned, index, field_ident));
+        }
+
+        mark.extend(invalidate(&self.struct_props.cached, field_ident));
+
+        match self.struct_props.track_dirty {
+            Some(TrackDirty {
+                skip_unchanged: true,
+            }) => {
+                before.extend(quote! { let changed = self.#field_ident != #new_val; });
+                after.extend(quote! {
+                    if changed {
+                        #mark
+                    }
+                });
+            }
+            _ => after.extend(mark),
+        }
+
+        let record_history = field_props.history && self.struct_props.history.is_some();
+
+        // Whether the replaced value is still needed after the write, as `old`.
+        let keep_old = returns_old
+            || func_props.after.is_some()
+            || record_history
+            || self
+                .struct_props
+                .observable
+                .as_ref()
+                .is_some_and(|observable| observable.values);
+
+        if let Some(after_hook) = &func_props.after {
+            after.extend(quote! { #after_hook(self, &old, &self.#field_ident); });
+        }
+
+        if let Some(observable) = &self.struct_props.observable {
+            after.extend(notify(self.struct_ident, observable, field_ident));
+        }
+
+        if let Some(events) = &self.struct_props.events {
+            after.extend(emit(self.struct_ident, events, field_ident, field_props));
+        }
+
+        // Moves `old`, so it comes last, unless it is also returned.
+        if record_history {
+            let old = if returns_old {
+                quote! { ::core::clone::Clone::clone(&old) }
+            } else {
+                quote! { old }
+            };
+            after.extend(record(self.struct_ident, field_ident, old));
+        }
+
+        let ret = match (&func_props.before, &func_props.error) {
+            _ if returns_old => quote! { old },
+            (Some(_), Some(_)) => quote! { ::core::result::Result::Ok(()) },
+            (Some(_), None) => quote! { true },
+            (None, _) => quote! {},
+        };
+
+        let write = if keep_old {
+            quote! { let old = ::core::mem::replace(&mut self.#field_ident, #new_val); }
+        } else {
+            quote! { self.#field_ident = #new_val; }
+        };
+
+        quote! {
+            #before
+            #write
+            #after
+            #ret
+        }
+    }
+
+    /// Run by the generated mutators other than setters after writing to the field at `index`.
+    pub(crate) fn after_write(&self, index: usize, field_ident: &Ident) -> TokenStream {
+        let mut after = quote! {};
+
+        if self.struct_props.track_dirty.is_some() {
+            after.extend(mark_dirty(index));
+        }
+
+        if let Some(versioned) = &self.struct_props.versioned {
+            after.extend(bump(versioned, index, field_ident));
+        }
+
+        after.extend(invalidate(&self.struct_props.cached, field_ident));
+
+        after
+    }
+}
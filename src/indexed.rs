@@ -0,0 +1,137 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Error, Ident, ItemStruct, Result, Type};
+
+use crate::enums::{IndexedFn, Kind};
+use crate::hooks::WriteHooks;
+use crate::props::{FieldProps, FuncProps};
+use crate::ty::type_args;
+
+fn index_error_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}IndexError", struct_ident)
+}
+
+/// `[T; N]` for arrays and `[T]` for `Vec<T>` and `Box<[T]>` fields.
+pub(crate) fn sequence_ty(ty: &Type) -> Result<Type> {
+    if let Type::Array(_) = ty {
+        return Ok(ty.clone());
+    }
+
+    if let Some([item]) = type_args(ty, &["Vec"]).as_deref() {
+        return Ok(parse_quote! { [#item] });
+    }
+
+    match type_args(ty, &["Box"]).as_deref() {
+        Some([Type::Slice(slice)]) => Ok(Type::Slice(slice.clone())),
+        _ => Err(Error::new_spanned(
+            ty,
+            "`indexed` requires a `[T; N]`, `Vec<T>` or `Box<[T]>` field",
+        )),
+    }
+}
+
+/// The signature and body of a function of the `indexed` preset.
+pub(crate) fn expand_indexed_fn(
+    indexed_fn: IndexedFn,
+    index: usize,
+    field_ident: &Ident,
+    func_props: &FuncProps,
+    hooks: &WriteHooks,
+) -> (TokenStream, TokenStream) {
+    let error_ident = index_error_ident(hooks.struct_ident);
+    // Set to the sequence type when the flag was parsed.
+    let (item, len) = match &func_props.ty {
+        Some(Type::Array(array)) => (&*array.elem, Some(&array.len)),
+        Some(Type::Slice(slice)) => (&*slice.elem, None),
+        _ => unreachable!(),
+    };
+
+    match indexed_fn {
+        IndexedFn::At => (
+            quote! { (&self, index: usize) -> ::core::option::Option<&#item> },
+            quote! { self.#field_ident.get(index) },
+        ),
+        // Handing out `&mut` counts as a write.
+        IndexedFn::AtMut => {
+            let after_write = hooks.after_write(index, field_ident);
+
+            (
+                quote! { (&mut self, index: usize) -> ::core::option::Option<&mut #item> },
+                quote! {
+                    #after_write
+                    self.#field_ident.get_mut(index)
+                },
+            )
+        }
+        IndexedFn::SetAt => {
+            let after_write = hooks.after_write(index, field_ident);
+
+            (
+                quote! {
+                    (&mut self, index: usize, value: #item) -> ::core::result::Result<#item, #error_ident>
+                },
+                quote! {
+                    let len = self.#field_ident.len();
+                    let ::core::option::Option::Some(slot) = self.#field_ident.get_mut(index) else {
+                        return ::core::result::Result::Err(#error_ident { index, len });
+                    };
+                    let old = ::core::mem::replace(slot, value);
+                    #after_write
+                    ::core::result::Result::Ok(old)
+                },
+            )
+        }
+        IndexedFn::Slice => (
+            quote! { (&self) -> &[#item] },
+            quote! { &self.#field_ident[..] },
+        ),
+        // Only generated for arrays.
+        IndexedFn::Get => {
+            let len = len.unwrap();
+
+            (
+                quote! { <const I: usize>(&self) -> &#item },
+                quote! {
+                    const { assert!(I < #len, "index out of bounds") };
+                    &self.#field_ident[I]
+                },
+            )
+        }
+    }
+}
+
+/// `ExampleIndexError`, returned by `set_x_at` out of bounds.
+pub(crate) fn expand_index_error(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+) -> Option<TokenStream> {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let error_ident = index_error_ident(struct_ident);
+
+    field_map
+        .iter()
+        .flat_map(|(_, field_props)| &field_props.props)
+        .any(|func_props| func_props.kind == Kind::Indexed(IndexedFn::SetAt))
+        .then(|| {
+            let error_doc =
+                format!("The error returned by the `set_x_at` functions of [`{struct_ident}`].");
+
+            quote! {
+                #[doc = #error_doc]
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                #vis struct #error_ident {
+                    pub index: usize,
+                    pub len: usize,
+                }
+
+                impl ::core::fmt::Display for #error_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "index {} out of bounds for length {}", self.index, self.len)
+                    }
+                }
+
+                impl ::std::error::Error for #error_ident {}
+            }
+        })
+}
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Visibility;
+
+/// The `Lens` trait, the `FieldLens` returned by `#[gsflags(lens)]` and the `Then` composing them.
+pub(crate) fn expand_lens_traits(vis: Visibility) -> TokenStream {
+    quote! {
+        #vis trait Lens<S: ?Sized> {
+            type Target: ?Sized;
+
+            fn get<'s>(&self, source: &'s S) -> &'s Self::Target
+            where
+                Self: 's;
+
+            fn get_mut<'s>(&self, source: &'s mut S) -> &'s mut Self::Target
+            where
+                Self: 's;
+
+            /// Focuses `next` on the target of this lens.
+            fn then<L: Lens<Self::Target>>(self, next: L) -> Then<Self, L>
+            where
+                Self: Sized,
+            {
+                Then(self, next)
+            }
+        }
+
+        /// Focuses on a single field of `S`.
+        #vis struct FieldLens<S: ?Sized, T: ?Sized> {
+            get: fn(&S) -> &T,
+            get_mut: fn(&mut S) -> &mut T,
+        }
+
+        impl<S: ?Sized, T: ?Sized> FieldLens<S, T> {
+            pub const fn new(get: fn(&S) -> &T, get_mut: fn(&mut S) -> &mut T) -> Self {
+                Self { get, get_mut }
+            }
+        }
+
+        impl<S: ?Sized, T: ?Sized> ::core::clone::Clone for FieldLens<S, T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<S: ?Sized, T: ?Sized> ::core::marker::Copy for FieldLens<S, T> {}
+
+        impl<S: ?Sized, T: ?Sized> Lens<S> for FieldLens<S, T> {
+            type Target = T;
+
+            #[inline]
+            fn get<'s>(&self, source: &'s S) -> &'s T
+            where
+                Self: 's,
+            {
+                (self.get)(source)
+            }
+
+            #[inline]
+            fn get_mut<'s>(&self, source: &'s mut S) -> &'s mut T
+            where
+                Self: 's,
+            {
+                (self.get_mut)(source)
+            }
+        }
+
+        /// Two lenses applied one after the other, see `Lens::then`.
+        #[derive(Clone, Copy, Debug)]
+        #vis struct Then<A, B>(pub A, pub B);
+
+        impl<S: ?Sized, A: Lens<S>, B: Lens<A::Target>> Lens<S> for Then<A, B> {
+            type Target = B::Target;
+
+            #[inline]
+            fn get<'s>(&self, source: &'s S) -> &'s Self::Target
+            where
+                Self: 's,
+            {
+                self.1.get(self.0.get(source))
+            }
+
+            #[inline]
+            fn get_mut<'s>(&self, source: &'s mut S) -> &'s mut Self::Target
+            where
+                Self: 's,
+            {
+                self.1.get_mut(self.0.get_mut(source))
+            }
+        }
+    }
+}
@@ -65,9 +65,36 @@
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, punctuated::Punctuated, Meta, Token};
 
+mod cache;
+mod case;
+mod collection;
+mod computed;
+mod delegate;
+mod diff;
+mod dirty;
 mod enums;
+mod events;
+mod field_enum;
+mod field_keys;
+mod flatten;
+mod history;
+mod hooks;
+mod indexed;
+mod lens;
+mod map;
+mod neutral;
+mod observe;
+mod option;
 mod parser;
+mod path_accessor;
+mod primitive;
+mod projection;
 mod props;
+mod reflect;
+mod shared_traits;
+mod ty;
+mod version;
+mod visit;
 
 #[proc_macro_attribute]
 pub fn get_set(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -80,3 +107,126 @@ pub fn get_set(attr: TokenStream, item: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+/// Declares the traits implemented by `#[gsflags(get(shared_trait), set(shared_trait))]`,
+/// so that unrelated structs can be used through the same field accessors.
+///
+/// ```rust
+/// use get_set_macro::{get_set, shared_traits};
+///
+/// // Declares `HasName { fn get_name(&self) -> &String; }`
+/// // and `HasNameMut { fn set_name(&mut self, new_name: String); }`
+/// shared_traits! {
+///     pub name: String,
+/// }
+///
+/// #[get_set]
+/// struct Person {
+///     #[gsflags(get(shared_trait), set(shared_trait))]
+///     name: String,
+/// }
+///
+/// #[get_set]
+/// struct Pet {
+///     #[gsflags(get(shared_trait))]
+///     name: String,
+/// }
+///
+/// fn greet<T: HasName>(t: &T) -> String {
+///     format!("Hello, {}!", t.get_name())
+/// }
+///
+/// fn main() {
+///     let person = Person { name: "Alice".to_string() };
+///     let pet = Pet { name: "Rex".to_string() };
+///
+///     assert_eq!("Hello, Alice!", greet(&person));
+///     assert_eq!("Hello, Rex!", greet(&pet));
+/// }
+/// ```
+#[proc_macro]
+pub fn shared_traits(input: TokenStream) -> TokenStream {
+    let decls = parse_macro_input!(
+        input with Punctuated::<shared_traits::SharedTraitDecl, Token![,]>::parse_terminated
+    );
+
+    shared_traits::expand_shared_traits(decls).into()
+}
+
+/// Declares the `Field`, `GetField` and `SetField` traits implemented by the field keys
+/// of `#[get_set(field_keys)]`, so generic code can be written once for any keyed field.
+///
+/// ```rust
+/// use get_set_macro::{field_traits, get_set};
+///
+/// field_traits!(pub);
+///
+/// #[get_set(field_keys, get, set)]
+/// struct Example {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// fn bind<S, F: GetField<S, Type = String>>(s: &S) -> String {
+///     format!("{} = {}", F::NAME, F::get(s))
+/// }
+///
+/// fn main() {
+///     let mut example = Example { name: "ExampleName".to_string(), age: 55 };
+///
+///     example.set::<example_fields::Age>(56);
+///
+///     assert_eq!(56, *example.get::<example_fields::Age>());
+///     assert_eq!("name = ExampleName", bind::<_, example_fields::Name>(&example));
+/// }
+/// ```
+#[proc_macro]
+pub fn field_traits(input: TokenStream) -> TokenStream {
+    let vis = parse_macro_input!(input as syn::Visibility);
+
+    field_keys::expand_field_traits(vis).into()
+}
+
+/// Declares the `Lens` trait, and the `FieldLens` and `Then` types it is built from,
+/// returned by the `x_lens()` functions of `#[gsflags(lens)]`.
+///
+/// ```rust
+/// use get_set_macro::{get_set, lens_traits};
+///
+/// lens_traits!(pub);
+///
+/// #[get_set]
+/// struct Inner {
+///     #[gsflags(lens)]
+///     x: i32,
+/// }
+///
+/// #[get_set]
+/// struct Outer {
+///     #[gsflags(lens)]
+///     inner: Inner,
+/// }
+///
+/// fn main() {
+///     let mut outer = Outer { inner: Inner { x: 1 } };
+///     let x = Outer::inner_lens().then(Inner::x_lens());
+///
+///     *x.get_mut(&mut outer) += 1;
+///
+///     assert_eq!(2, *x.get(&outer));
+/// }
+/// ```
+#[proc_macro]
+pub fn lens_traits(input: TokenStream) -> TokenStream {
+    let vis = parse_macro_input!(input as syn::Visibility);
+
+    lens::expand_lens_traits(vis).into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __flatten(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as flatten::FlattenInput);
+
+    flatten::expand_flatten_accessors(input).into()
+}
@@ -0,0 +1,146 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Error, Ident, MetaNameValue, Result, Type};
+
+use crate::enums::{MapContainer, MapFn};
+use crate::hooks::WriteHooks;
+use crate::props::{expect_lit_str, FuncProps};
+use crate::ty::type_args;
+
+/// The map and its `(K, V)` types, from the field type or from
+/// the `map = ".."`, `key = ".."` and `value = ".."` settings for aliases.
+pub(crate) fn map_types(ty: &Type, settings: &[MetaNameValue]) -> Result<(MapContainer, Type)> {
+    let mut map = None;
+    let mut key = None;
+    let mut value = None;
+
+    for setting in settings {
+        if setting.path.is_ident("map") {
+            let name = expect_lit_str(setting.value.clone(), "map = \"HashMap\"")?;
+            map = Some(MapContainer::from_name(&name.value()).ok_or_else(|| {
+                Error::new_spanned(&name, "Valid maps are `HashMap` and `BTreeMap`")
+            })?);
+        } else if setting.path.is_ident("key") {
+            key = Some(expect_lit_str(setting.value.clone(), "key = \"K\"")?.parse::<Type>()?);
+        } else {
+            value = Some(expect_lit_str(setting.value.clone(), "value = \"V\"")?.parse::<Type>()?);
+        }
+    }
+
+    let args = type_args(ty, &MapContainer::NAMES).unwrap_or_default();
+    let detected = match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .and_then(|segment| MapContainer::from_name(&segment.ident.to_string())),
+        _ => None,
+    };
+
+    match (
+        map.or(detected),
+        key.or_else(|| args.first().cloned().cloned()),
+        value.or_else(|| args.get(1).cloned().cloned()),
+    ) {
+        (Some(map), Some(key), Some(value)) => Ok((map, parse_quote! { (#key, #value) })),
+        _ => Err(Error::new_spanned(
+            ty,
+            "`map` requires a `HashMap` or `BTreeMap` field, \
+             use `map(map = \"HashMap\", key = \"K\", value = \"V\")` for aliases",
+        )),
+    }
+}
+
+/// The signature and body of a function of the `map` preset.
+pub(crate) fn expand_map_fn(
+    map_fn: MapFn,
+    map: MapContainer,
+    index: usize,
+    field_ident: &Ident,
+    func_props: &FuncProps,
+    hooks: &WriteHooks,
+) -> (TokenStream, TokenStream) {
+    // Set to `(K, V)` when the flag was parsed.
+    let Some(Type::Tuple(key_value)) = &func_props.ty else {
+        unreachable!()
+    };
+    let (key, value) = (&key_value.elems[0], &key_value.elems[1]);
+    let after_write = hooks.after_write(index, field_ident);
+
+    // Looked up by anything the keys borrow as.
+    let (lookup_bounds, entry) = match map {
+        MapContainer::HashMap => (
+            quote! { ::core::hash::Hash + ::core::cmp::Eq },
+            quote! { ::std::collections::hash_map::Entry },
+        ),
+        MapContainer::BTreeMap => (
+            quote! { ::core::cmp::Ord },
+            quote! { ::std::collections::btree_map::Entry },
+        ),
+    };
+    let lookup = |receiver: TokenStream, ret: TokenStream| {
+        quote! {
+            <Q: ?::core::marker::Sized + #lookup_bounds>(#receiver, key: &Q) -> #ret
+            where
+                #key: ::core::borrow::Borrow<Q>
+        }
+    };
+
+    match map_fn {
+        MapFn::Get => (
+            lookup(quote! { &self }, quote! { ::core::option::Option<&#value> }),
+            quote! { self.#field_ident.get(key) },
+        ),
+        // Handing out `&mut` counts as a write.
+        MapFn::GetMut => (
+            lookup(
+                quote! { &mut self },
+                quote! { ::core::option::Option<&mut #value> },
+            ),
+            quote! {
+                #after_write
+                self.#field_ident.get_mut(key)
+            },
+        ),
+        MapFn::Insert => (
+            quote! { (&mut self, key: #key, value: #value) -> ::core::option::Option<#value> },
+            quote! {
+                let old = self.#field_ident.insert(key, value);
+                #after_write
+                old
+            },
+        ),
+        MapFn::Remove => (
+            lookup(
+                quote! { &mut self },
+                quote! { ::core::option::Option<#value> },
+            ),
+            quote! {
+                let removed = self.#field_ident.remove(key);
+                if removed.is_some() {
+                    #after_write
+                }
+                removed
+            },
+        ),
+        MapFn::Entry => (
+            quote! { (&mut self, key: #key) -> #entry<'_, #key, #value> },
+            quote! {
+                #after_write
+                self.#field_ident.entry(key)
+            },
+        ),
+        MapFn::Keys => (
+            quote! { (&self) -> impl ::core::iter::Iterator<Item = &#key> + '_ },
+            quote! { self.#field_ident.keys() },
+        ),
+        MapFn::Values => (
+            quote! { (&self) -> impl ::core::iter::Iterator<Item = &#value> + '_ },
+            quote! { self.#field_ident.values() },
+        ),
+        MapFn::Len => (
+            quote! { (&self) -> usize },
+            quote! { self.#field_ident.len() },
+        ),
+    }
+}
@@ -0,0 +1,43 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Visibility};
+
+/// A newtype around the bookkeeping of a hidden field, `ty` starting at `zero`. Like the cache,
+/// it never affects comparisons or hashes, so the struct can keep deriving them.
+pub(crate) fn neutral_newtype(
+    vis: &Visibility,
+    ident: &Ident,
+    ty: TokenStream,
+    zero: TokenStream,
+    doc: &str,
+) -> TokenStream {
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy)]
+        #vis struct #ident(#ty);
+
+        impl ::core::default::Default for #ident {
+            fn default() -> Self {
+                Self(#zero)
+            }
+        }
+
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for #ident {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl ::core::cmp::Eq for #ident {}
+
+        impl ::core::hash::Hash for #ident {
+            fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+        }
+    }
+}
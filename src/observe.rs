@@ -0,0 +1,250 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, GenericParam, Generics, Ident, ItemStruct, Lifetime};
+
+use crate::case::{field_name, to_camel_case};
+use crate::diff::phantom_variant;
+use crate::field_enum::field_enum_ident;
+use crate::props::{FieldProps, Observable};
+
+pub(crate) fn event_ident(struct_ident: &Ident, observable: &Observable) -> Ident {
+    observable
+        .name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}Event", struct_ident))
+}
+
+fn observers_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}Observers", struct_ident)
+}
+
+/// `__observers: ExampleObservers`, the registry `subscribe` adds to.
+pub(crate) fn observers_field(input: &ItemStruct, observable: &Observable) -> TokenStream {
+    let vis = &input.vis;
+    let observers_ident = observers_ident(&input.ident);
+    // Events borrowing the values are generic over the struct's generics, and so are their observers.
+    let ty_generics = observable.values.then(|| input.generics.split_for_impl().1);
+
+    quote! {
+        #[doc(hidden)]
+        #vis __observers: #observers_ident #ty_generics
+    }
+}
+
+/// Emits the event for the field after a generated setter wrote to it,
+/// `old` is the value that was replaced when the event carries values.
+pub(crate) fn notify(
+    struct_ident: &Ident,
+    observable: &Observable,
+    field_ident: &Ident,
+) -> TokenStream {
+    let event_ident = event_ident(struct_ident, observable);
+    let variant = to_camel_case(field_ident);
+
+    if observable.values {
+        quote! {
+            self.__observers.notify(&#event_ident::#variant {
+                old: &old,
+                new: &self.#field_ident,
+            });
+        }
+    } else {
+        quote! { self.__observers.notify(&#event_ident::#variant); }
+    }
+}
+
+/// The event enum with a variant per field with a setter, the observer registry and `subscribe`.
+pub(crate) fn expand_observable(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    observable: &Observable,
+    field_enum: bool,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let event_ident = event_ident(struct_ident, observable);
+    let observers_ident = observers_ident(struct_ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = field_map
+        .iter()
+        .filter(|(_, field_props)| field_props.is_listed() && field_props.has_setter())
+        .collect::<Vec<_>>();
+
+    let variants = fields
+        .iter()
+        .map(|(field_ident, _)| to_camel_case(field_ident))
+        .collect::<Vec<_>>();
+    let names = fields
+        .iter()
+        .map(|(field_ident, _)| field_name(field_ident))
+        .collect::<Vec<_>>();
+
+    let lifetime: Lifetime = parse_quote! { '__a };
+
+    // Only events carrying values borrow from the struct and need its generics.
+    let (event_generics, observers_generics, phantom, phantom_arm) = if observable.values {
+        let mut event_generics = input.generics.clone();
+        event_generics
+            .params
+            .insert(0, GenericParam::Lifetime(parse_quote! { #lifetime }));
+        let (phantom, phantom_arm) = phantom_variant(input, Some(&lifetime), !fields.is_empty());
+
+        (event_generics, input.generics.clone(), phantom, phantom_arm)
+    } else {
+        (Generics::default(), Generics::default(), None, None)
+    };
+    let (event_impl_generics, event_ty_generics, _) = event_generics.split_for_impl();
+    let (observers_impl_generics, observers_ty_generics, observers_where_clause) =
+        observers_generics.split_for_impl();
+
+    let event_def = if observable.values {
+        let tys = fields.iter().map(|(_, field_props)| &field_props.ty);
+
+        quote! {
+            #[derive(Clone, Copy)]
+            #vis enum #event_ident #event_generics #where_clause {
+                #(#variants { old: &#lifetime #tys, new: &#lifetime #tys },)*
+                #phantom
+            }
+
+            // Only names the variant, so the field types need not implement `Debug`.
+            impl #event_impl_generics ::core::fmt::Debug for #event_ident #event_ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(Self::#variants { .. } => f.debug_struct(::core::stringify!(#variants)).finish_non_exhaustive(),)*
+                        #phantom_arm
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #vis enum #event_ident {
+                #(#variants,)*
+            }
+        }
+    };
+
+    // The event as seen by observers, any lifetime it borrows for is elided.
+    let event_ty = if observable.values {
+        let args = input.generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote! { #lifetime }
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote! { #ident }
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote! { #ident }
+            }
+        });
+
+        quote! { #event_ident<'_, #(#args,)*> }
+    } else {
+        quote! { #event_ident }
+    };
+
+    // An event without values has no phantom variant, so an enum without variants is matched by value.
+    let scrutinee = if observable.values {
+        quote! { self }
+    } else {
+        quote! { *self }
+    };
+
+    let field_fn = field_enum.then(|| {
+        let enum_ident = field_enum_ident(struct_ident);
+
+        quote! {
+            #vis fn field(&self) -> #enum_ident {
+                match #scrutinee {
+                    #(Self::#variants { .. } => #enum_ident::#variants,)*
+                    #phantom_arm
+                }
+            }
+        }
+    });
+
+    let event_doc =
+        format!("Emitted by the setters of [`{struct_ident}`], see [`{struct_ident}::subscribe`].");
+    let observers_doc = format!(
+        "The observers subscribed to a [`{struct_ident}`]. They are not cloned, and never affect comparisons. \
+        They need not be `Send`, so neither is the struct."
+    );
+
+    quote! {
+        #[doc = #event_doc]
+        #event_def
+
+        impl #event_impl_generics #event_ident #event_ty_generics #where_clause {
+            #vis fn field_name(&self) -> &'static str {
+                match #scrutinee {
+                    #(Self::#variants { .. } => #names,)*
+                    #phantom_arm
+                }
+            }
+
+            #field_fn
+        }
+
+        #[doc = #observers_doc]
+        #vis struct #observers_ident #observers_generics #observers_where_clause {
+            observers: ::std::vec::Vec<::std::boxed::Box<dyn ::core::ops::FnMut(&#event_ty)>>,
+        }
+
+        impl #observers_impl_generics #observers_ident #observers_ty_generics #observers_where_clause {
+            fn notify(&mut self, event: &#event_ty) {
+                for observer in &mut self.observers {
+                    observer(event);
+                }
+            }
+        }
+
+        impl #observers_impl_generics ::core::default::Default for #observers_ident #observers_ty_generics #observers_where_clause {
+            fn default() -> Self {
+                Self {
+                    observers: ::std::vec::Vec::new(),
+                }
+            }
+        }
+
+        impl #observers_impl_generics ::core::clone::Clone for #observers_ident #observers_ty_generics #observers_where_clause {
+            fn clone(&self) -> Self {
+                ::core::default::Default::default()
+            }
+        }
+
+        impl #observers_impl_generics ::core::fmt::Debug for #observers_ident #observers_ty_generics #observers_where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!(#observers_ident))
+                    .field("len", &self.observers.len())
+                    .finish()
+            }
+        }
+
+        impl #observers_impl_generics ::core::cmp::PartialEq for #observers_ident #observers_ty_generics #observers_where_clause {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl #observers_impl_generics ::core::cmp::Eq for #observers_ident #observers_ty_generics #observers_where_clause {}
+
+        impl #observers_impl_generics ::core::hash::Hash for #observers_ident #observers_ty_generics #observers_where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+        }
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Calls `observer` with an event after every write of a generated setter.
+            ///
+            /// Observers are not required to be `Send` or `Sync`, so the struct is neither.
+            #vis fn subscribe(&mut self, observer: impl ::core::ops::FnMut(&#event_ty) + 'static) {
+                self.__observers.observers.push(::std::boxed::Box::new(observer));
+            }
+        }
+    }
+}
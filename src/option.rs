@@ -0,0 +1,95 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Ident, Result, Type};
+
+use crate::enums::OptionFn;
+use crate::hooks::WriteHooks;
+use crate::props::{FieldProps, FuncProps};
+use crate::ty::type_args;
+
+/// `T` of an `Option<T>` field.
+pub(crate) fn option_inner_ty(ty: &Type) -> Result<&Type> {
+    match type_args(ty, &["Option"]).as_deref() {
+        Some([inner]) => Ok(inner),
+        _ => Err(Error::new_spanned(
+            ty,
+            "`option` requires an `Option<T>` field",
+        )),
+    }
+}
+
+/// The signature and body of a function of the `option` preset.
+pub(crate) fn expand_option_fn(
+    option_fn: OptionFn,
+    index: usize,
+    field_ident: &Ident,
+    field_props: &FieldProps,
+    func_props: &FuncProps,
+    hooks: &WriteHooks,
+) -> (TokenStream, TokenStream) {
+    // Checked when the flag was parsed.
+    let inner = option_inner_ty(&field_props.ty).unwrap();
+
+    match option_fn {
+        OptionFn::Get => (
+            quote! { (&self) -> ::core::option::Option<&#inner> },
+            quote! { self.#field_ident.as_ref() },
+        ),
+        // Handing out `&mut` counts as a write.
+        OptionFn::GetMut => {
+            let after_write = hooks.after_write(index, field_ident);
+
+            (
+                quote! { (&mut self) -> ::core::option::Option<&mut #inner> },
+                quote! {
+                    #after_write
+                    self.#field_ident.as_mut()
+                },
+            )
+        }
+        // A setter in every other way, hooks and bookkeeping included.
+        OptionFn::Set => {
+            let new_val_name = format_ident!("new_{}", field_ident);
+            let setter_body =
+                hooks.setter_body(index, field_ident, field_props, &new_val_name, func_props);
+            let ret = func_props.setter_ret();
+
+            (
+                quote! { (&mut self, #new_val_name: #inner) #ret },
+                quote! {
+                    let #new_val_name = ::core::option::Option::Some(#new_val_name);
+                    #setter_body
+                },
+            )
+        }
+        // A setter of `None`, returning the value it took.
+        OptionFn::Clear => {
+            let new_val_name = format_ident!("new_{}", field_ident);
+            let replacer_body =
+                hooks.replacer_body(index, field_ident, field_props, &new_val_name, func_props);
+
+            (
+                quote! { (&mut self) -> ::core::option::Option<#inner> },
+                quote! {
+                    let #new_val_name = ::core::option::Option::None;
+                    #replacer_body
+                },
+            )
+        }
+        OptionFn::Has => (
+            quote! { (&self) -> bool },
+            quote! { self.#field_ident.is_some() },
+        ),
+        OptionFn::GetOrInsertWith => {
+            let after_write = hooks.after_write(index, field_ident);
+
+            (
+                quote! { (&mut self, f: impl ::core::ops::FnOnce() -> #inner) -> &mut #inner },
+                quote! {
+                    #after_write
+                    self.#field_ident.get_or_insert_with(f)
+                },
+            )
+        }
+    }
+}
@@ -1,11 +1,40 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{punctuated::Punctuated, Error, Fields, Ident, ItemStruct, Meta, Result, Token};
+use syn::{
+    parse::Parser, punctuated::Punctuated, Error, Field, Fields, Ident, ItemStruct, Meta,
+    MetaNameValue, Path, Result, Token, Type, Visibility,
+};
 
-use crate::enums::Kind;
-use crate::props::{FieldProps, FuncProps, OptFuncProps, OptFuncPropsWithKind};
+use crate::cache::{cache_field, expand_cache, expand_cached_getter};
+use crate::collection::{collection_types, expand_collection_fn};
+use crate::computed::expand_computed;
+use crate::delegate::expand_delegate;
+use crate::diff::expand_diff;
+use crate::dirty::{dirty_field, expand_dirty};
+use crate::enums::{
+    BoolFn, CollectionFn, IndexedFn, Kind, MapFn, NumericFn, OptionFn, Projection, Target,
+};
+use crate::events::{self, expand_events};
+use crate::field_enum::expand_field_enum;
+use crate::field_keys::expand_field_keys;
+use crate::flatten::{expand_flatten, expand_flattenable};
+use crate::history::{expand_history, history_field};
+use crate::hooks::WriteHooks;
+use crate::indexed::{expand_index_error, expand_indexed_fn, sequence_ty};
+use crate::map::{expand_map_fn, map_types};
+use crate::observe::{self, expand_observable, observers_field};
+use crate::option::{expand_option_fn, option_inner_ty};
+use crate::path_accessor::expand_path_accessor;
+use crate::primitive::{check_bool, expand_bool_fn, expand_numeric_fn, numeric_mode};
+use crate::projection::expand_projections;
+use crate::props::{
+    Delegate, FieldProps, Flatten, FuncProps, OptFuncProps, OptFuncPropsWithKind, StructProps,
+};
+use crate::reflect::expand_reflect;
+use crate::version::{expand_versioned, field_versions_field, version_field};
+use crate::visit::expand_visit;
 
 pub fn expand_get_set(
     gs_attrs: Option<Punctuated<Meta, Token![,]>>,
@@ -13,6 +42,7 @@ pub fn expand_get_set(
 ) -> Result<TokenStream> {
     let mut all_func_props = Vec::new();
     let mut all_default_func_props = OptFuncProps::new();
+    let mut struct_props = StructProps::new();
 
     if let Some(gs_attrs) = gs_attrs {
         all_default_func_props = extract_default_func_props(&gs_attrs)?;
@@ -21,34 +51,55 @@ pub fn expand_get_set(
             .into_iter()
             .filter(|gs_flag| !gs_flag.path().is_ident("default"))
         {
+            if struct_props.try_insert(&gs_flag)? {
+                continue;
+            }
+
+            let opt_func_props = extract_opt_func_props(&gs_flag)?;
+
+            // Struct-wide functions drop the settings of single functions, hooks would be lost silently.
+            if opt_func_props.before.is_some()
+                || opt_func_props.after.is_some()
+                || opt_func_props.error.is_some()
+            {
+                return Err(Error::new_spanned(
+                    gs_flag,
+                    "`before`, `after` and `error` are only supported on the `set` of a field",
+                ));
+            }
+
             all_func_props.push(OptFuncPropsWithKind {
-                optfuncprops: extract_opt_func_props(&gs_flag)?.or(all_default_func_props.clone()),
+                optfuncprops: opt_func_props.or(all_default_func_props.clone()),
                 kind: gs_flag.try_into()?,
             })
         }
     }
 
-    let struct_ident = &input.ident;
+    let struct_ident = input.ident.clone();
     let fields = if let Fields::Named(fields_named) = &mut input.fields {
         &mut fields_named.named
     } else {
         return Err(Error::new_spanned(input, "Only named fields are supported"));
     };
 
-    let mut field_map: HashMap<Ident, FieldProps> = HashMap::new();
+    // In declaration order, which is the order of everything that enumerates fields.
+    let mut field_map: Vec<(Ident, FieldProps)> = Vec::new();
 
     for field in fields {
         let field_ident = field.ident.clone().unwrap();
 
-        let ref mut field_props =
-            field_map
-                .entry(field.ident.clone().unwrap())
-                .or_insert(FieldProps {
-                    ty: field.ty.clone(),
-                    all_skip: false,
-                    props: HashSet::new(),
-                });
+        let mut field_props = FieldProps {
+            ty: field.ty.clone(),
+            all_skip: false,
+            history: false,
+            props: HashSet::new(),
+            delegates: Vec::new(),
+            projections: Vec::new(),
+            flatten: None,
+        };
 
+        // Explicit flags of the field win over its presets, whatever their order.
+        let mut preset_props = Vec::new();
         let mut remove_attrs = vec![];
 
         for (i, attr) in field.attrs.iter_mut().enumerate() {
@@ -73,6 +124,166 @@ pub fn expand_get_set(
                             continue;
                         }
 
+                        if gs_flag.path().is_ident("history") {
+                            field_props.history = true;
+                            continue;
+                        }
+
+                        if let Some(projection) = Projection::from_flag(&gs_flag) {
+                            field_props.projections.push(projection);
+                            continue;
+                        }
+
+                        // A preset generates a set of functions with the same settings.
+                        if gs_flag.path().is_ident("option") {
+                            option_inner_ty(&field_props.ty)?;
+
+                            let (_, opt_func_props) = extract_preset_settings(&gs_flag, &[])?;
+                            let opt_func_props = opt_func_props
+                                .or(default_func_props.clone())
+                                .remove_specific();
+                            preset_props.extend(OptionFn::ALL.map(|option_fn| {
+                                opt_func_props
+                                    .clone()
+                                    .build(Kind::Option(option_fn), &field_ident)
+                            }));
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("collection") {
+                            let (settings, opt_func_props) =
+                                extract_preset_settings(&gs_flag, &["item", "container"])?;
+                            let (container, item) = collection_types(&field_props.ty, &settings)?;
+
+                            // The item type is carried as the type override.
+                            let opt_func_props = OptFuncProps {
+                                ty: Some(item),
+                                ..opt_func_props
+                                    .or(default_func_props.clone())
+                                    .remove_specific()
+                            };
+                            preset_props.extend(CollectionFn::ALL.map(|collection_fn| {
+                                opt_func_props
+                                    .clone()
+                                    .build(Kind::Collection(collection_fn, container), &field_ident)
+                            }));
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("map") {
+                            let (settings, opt_func_props) =
+                                extract_preset_settings(&gs_flag, &["map", "key", "value"])?;
+                            let (map, key_value) = map_types(&field_props.ty, &settings)?;
+
+                            // The `(K, V)` types are carried as the type override.
+                            let opt_func_props = OptFuncProps {
+                                ty: Some(key_value),
+                                ..opt_func_props
+                                    .or(default_func_props.clone())
+                                    .remove_specific()
+                            };
+                            preset_props.extend(MapFn::ALL.map(|map_fn| {
+                                opt_func_props
+                                    .clone()
+                                    .build(Kind::Map(map_fn, map), &field_ident)
+                            }));
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("indexed") {
+                            let sequence = sequence_ty(&field_props.ty)?;
+                            // Only arrays have a length to check `x_get::<I>()` against.
+                            let is_array = matches!(sequence, Type::Array(_));
+                            let indexed_fns = IndexedFn::ALL
+                                .into_iter()
+                                .filter(|indexed_fn| *indexed_fn != IndexedFn::Get || is_array);
+
+                            // The sequence type is carried as the type override.
+                            let (_, opt_func_props) = extract_preset_settings(&gs_flag, &[])?;
+                            let opt_func_props = OptFuncProps {
+                                ty: Some(sequence),
+                                ..opt_func_props
+                                    .or(default_func_props.clone())
+                                    .remove_specific()
+                            };
+                            preset_props.extend(indexed_fns.map(|indexed_fn| {
+                                opt_func_props
+                                    .clone()
+                                    .build(Kind::Indexed(indexed_fn), &field_ident)
+                            }));
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("numeric") {
+                            let (settings, opt_func_props) =
+                                extract_preset_settings(&gs_flag, &["mode"])?;
+                            let mode = numeric_mode(&field_props.ty, &settings)?;
+
+                            let opt_func_props = opt_func_props
+                                .or(default_func_props.clone())
+                                .remove_specific();
+                            preset_props.extend(NumericFn::ALL.map(|numeric_fn| {
+                                opt_func_props
+                                    .clone()
+                                    .build(Kind::Numeric(numeric_fn, mode), &field_ident)
+                            }));
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("bool") {
+                            check_bool(&field_props.ty)?;
+
+                            let (_, opt_func_props) = extract_preset_settings(&gs_flag, &[])?;
+                            let opt_func_props = opt_func_props
+                                .or(default_func_props.clone())
+                                .remove_specific();
+                            preset_props.extend(BoolFn::ALL.map(|bool_fn| {
+                                opt_func_props
+                                    .clone()
+                                    .build(Kind::Bool(bool_fn), &field_ident)
+                            }));
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("flatten") {
+                            let mut flatten = Flatten::try_from(&gs_flag)?;
+                            flatten.opt_func_props = flatten
+                                .opt_func_props
+                                .or(default_func_props.clone().remove_specific());
+                            field_props.flatten = Some(flatten);
+                            continue;
+                        }
+
+                        if gs_flag.path().is_ident("delegate") {
+                            let Meta::List(list) = &gs_flag else {
+                                return Err(Error::new_spanned(
+                                    gs_flag,
+                                    "delegate usage: `delegate(pub fn len(&self) -> usize, ...)`",
+                                ));
+                            };
+
+                            // Without a visibility of their own, delegates take the default one.
+                            let default_func_props = default_func_props.clone();
+                            field_props.delegates.extend(
+                                list.parse_args_with(
+                                    Punctuated::<Delegate, Token![,]>::parse_terminated,
+                                )?
+                                .into_iter()
+                                .map(|delegate| Delegate {
+                                    vis: match delegate.vis {
+                                        Visibility::Inherited => default_func_props
+                                            .vis
+                                            .clone()
+                                            .unwrap_or(Visibility::Inherited),
+                                        vis => vis,
+                                    },
+                                    inline: default_func_props.inline.unwrap_or_default(),
+                                    ..delegate
+                                }),
+                            );
+                            continue;
+                        }
+
                         field_props.props.insert(
                             extract_opt_func_props(&gs_flag)?
                                 .or(default_func_props.clone())
@@ -90,6 +301,8 @@ pub fn expand_get_set(
             }
         }
 
+        field_props.props.extend(preset_props);
+
         if !field_props.all_skip {
             field_props.props.extend(
                 all_func_props
@@ -98,77 +311,431 @@ pub fn expand_get_set(
             );
         }
 
-        remove_attrs.into_iter().for_each(|i| {
+        remove_attrs.into_iter().rev().for_each(|i| {
             field.attrs.remove(i);
         });
+
+        field_map.push((field_ident, field_props));
     }
 
-    let impl_contents = field_map.into_iter().fold(
-        quote! {},
-        |acc,
-         (
-            field_ident,
-            FieldProps {
-                ty,
-                all_skip: _,
-                props,
-            },
-        )| {
-            props.into_iter().fold(
-                acc,
-                |acc,
-                 FuncProps {
-                     kind,
-                     name,
-                     inline,
-                     vis,
-                 }| {
-                    let (sig, body) = match kind {
-                        Kind::Setr => {
-                            let new_val_name = format_ident!("new_{field_ident}");
-
-                            let sig = quote! { (&mut self, #new_val_name: #ty) };
-                            let body = quote! { self.#field_ident = #new_val_name; };
-
-                            (sig, body)
-                        }
-                        Kind::GetrRef | Kind::GetrCopy => {
-                            let amp = if kind == Kind::GetrRef
-                                && !matches!(ty, syn::Type::Reference(_))
-                            {
-                                quote! { & }
-                            } else {
-                                quote! {}
-                            };
+    if struct_props.track_dirty.is_some() {
+        let dirty_field = dirty_field(&input);
+        inject_field(&mut input, dirty_field)?;
+    }
 
-                            let sig = quote! { (&self) -> #amp #ty };
-                            let body = quote! { #amp self.#field_ident };
+    if let Some(observable) = &struct_props.observable {
+        let observers_field = observers_field(&input, observable);
+        inject_field(&mut input, observers_field)?;
+    }
 
-                            (sig, body)
-                        }
-                    };
+    if struct_props.history.is_some() {
+        let history_field = history_field(&input);
+        inject_field(&mut input, history_field)?;
+    }
 
-                    quote! {
-                        #acc
-                        #inline
-                        #vis fn #name #sig {
-                            #body
-                        }
+    if !struct_props.cached.is_empty() {
+        let cache_field = cache_field(&input);
+        inject_field(&mut input, cache_field)?;
+    }
+
+    if let Some(versioned) = &mut struct_props.versioned {
+        if field_map
+            .iter()
+            .any(|(field_ident, _)| field_ident == "version")
+        {
+            versioned.field = format_ident!("version");
+        }
+
+        if let Some(version_field) = version_field(&input, versioned) {
+            inject_field(&mut input, version_field)?;
+        }
+
+        if versioned.per_field {
+            let field_versions_field = field_versions_field(&input);
+            inject_field(&mut input, field_versions_field)?;
+        }
+    }
+
+    let mut impl_contents = quote! {};
+    let mut trait_impl_contents = quote! {};
+    let mut shared_trait_impls: Vec<(Path, TokenStream)> = Vec::new();
+    let mut flattened = quote! {};
+
+    let hooks = WriteHooks {
+        struct_ident: &struct_ident,
+        struct_props: &struct_props,
+    };
+
+    for (index, (field_ident, field_props)) in field_map.iter().enumerate() {
+        if field_props.history && struct_props.history.is_none() {
+            return Err(Error::new_spanned(
+                field_ident,
+                "`history` requires the history to be enabled, e.g. `#[get_set(history(capacity = 100))]`",
+            ));
+        }
+
+        if let Some(flatten) = &field_props.flatten {
+            flattened.extend(expand_flatten(
+                &input,
+                index,
+                field_ident,
+                field_props,
+                flatten,
+                &hooks,
+            )?);
+        }
+
+        for delegate in &field_props.delegates {
+            impl_contents.extend(expand_delegate(index, field_ident, delegate, &hooks)?);
+        }
+
+        for func_props in &field_props.props {
+            if func_props.kind != Kind::Setr
+                && (func_props.before.is_some()
+                    || func_props.after.is_some()
+                    || func_props.error.is_some())
+            {
+                return Err(Error::new_spanned(
+                    &func_props.name,
+                    "`before`, `after` and `error` are only supported on `set`",
+                ));
+            }
+
+            if func_props.error.is_some() && func_props.before.is_none() {
+                return Err(Error::new_spanned(
+                    &func_props.name,
+                    "`error` is the error type of the `before` hook, which is missing",
+                ));
+            }
+
+            let func = expand_func(index, field_ident, field_props, func_props, &hooks);
+
+            match func_props.target.clone() {
+                Target::Inherent => impl_contents.extend(func),
+                Target::ImplTrait => trait_impl_contents.extend(func),
+                Target::SharedTrait(_)
+                    if !matches!(func_props.kind, Kind::GetrRef | Kind::Setr) =>
+                {
+                    return Err(Error::new_spanned(
+                        field_ident,
+                        "`shared_trait` is only supported on `get` and `set`",
+                    ));
+                }
+                Target::SharedTrait(path) => {
+                    match shared_trait_impls
+                        .iter_mut()
+                        .find(|(other, _)| *other == path)
+                    {
+                        Some((_, contents)) => contents.extend(func),
+                        None => shared_trait_impls.push((path, func)),
                     }
-                },
-            )
+                }
+            }
+        }
+    }
+
+    for computed in &struct_props.computed {
+        let func_props = computed
+            .opt_func_props
+            .clone()
+            .or(all_default_func_props.clone())
+            .build(Kind::GetrCopy, &computed.name);
+
+        let func = expand_computed(computed, &func_props)?;
+
+        match func_props.target {
+            Target::ImplTrait => trait_impl_contents.extend(func),
+            _ => impl_contents.extend(func),
+        }
+    }
+
+    for cached in &struct_props.cached {
+        let func_props = cached
+            .opt_func_props
+            .clone()
+            .or(all_default_func_props.clone())
+            .build(Kind::GetrRef, &cached.name);
+
+        let func = expand_cached_getter(&field_map, cached, &func_props)?;
+
+        match func_props.target {
+            Target::ImplTrait => trait_impl_contents.extend(func),
+            _ => impl_contents.extend(func),
+        }
+    }
+
+    for path_accessor in &struct_props.paths {
+        impl_contents.extend(expand_path_accessor(
+            &field_map,
+            path_accessor,
+            &all_default_func_props,
+            &hooks,
+        )?);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let shared_trait_impls = shared_trait_impls.into_iter().map(|(path, contents)| {
+        quote! {
+            impl #impl_generics #path for #struct_ident #ty_generics #where_clause {
+                #contents
+            }
+        }
+    });
+
+    let field_keys = if struct_props.field_keys {
+        expand_field_keys(&input, &field_map, struct_props.impl_trait.as_ref())
+    } else {
+        quote! {}
+    };
+
+    let field_enum = if struct_props.field_enum {
+        expand_field_enum(&input, &field_map)
+    } else {
+        quote! {}
+    };
+
+    let reflect = if struct_props.reflect {
+        expand_reflect(&input, &field_map, struct_props.impl_trait.as_ref(), &hooks)
+    } else {
+        quote! {}
+    };
+
+    let visit = if struct_props.visit {
+        expand_visit(&input, &field_map, &hooks)?
+    } else {
+        quote! {}
+    };
+
+    let diff = if struct_props.diff {
+        expand_diff(&input, &field_map, struct_props.field_enum)
+    } else {
+        quote! {}
+    };
+
+    let dirty = if struct_props.track_dirty.is_some() {
+        expand_dirty(&input, &field_map)
+    } else {
+        quote! {}
+    };
+
+    let observable = match &struct_props.observable {
+        Some(observable) => {
+            expand_observable(&input, &field_map, observable, struct_props.field_enum)
+        }
+        None => quote! {},
+    };
+
+    let history = match &struct_props.history {
+        Some(history) => expand_history(&input, &field_map, history, &hooks),
+        None => quote! {},
+    };
+
+    let cache = if struct_props.cached.is_empty() {
+        quote! {}
+    } else {
+        expand_cache(&input, &struct_props.cached)
+    };
+
+    let projections = expand_projections(&input, &field_map)?;
+
+    let index_error = expand_index_error(&input, &field_map);
+
+    let flattenable = if struct_props.flattenable {
+        expand_flattenable(&input, &field_map)?
+    } else {
+        quote! {}
+    };
+
+    let versioned = match &struct_props.versioned {
+        Some(versioned) => expand_versioned(&input, &field_map, versioned),
+        None => quote! {},
+    };
+
+    let events = match &struct_props.events {
+        Some(events) => {
+            if let Some(observable) = &struct_props.observable {
+                if observe::event_ident(&struct_ident, observable)
+                    == events::event_ident(&struct_ident, events)
+                {
+                    return Err(Error::new_spanned(
+                        &struct_ident,
+                        "`observable` and `events` both generate an event enum with the same name, rename one with `name = \"...\"`",
+                    ));
+                }
+            }
+
+            expand_events(&input, &field_map, events, struct_props.impl_trait.as_ref())
+        }
+        None => quote! {},
+    };
+
+    let trait_impl = match struct_props.impl_trait {
+        Some(impl_trait) => quote! {
+            impl #impl_generics #impl_trait for #struct_ident #ty_generics #where_clause {
+                #trait_impl_contents
+            }
         },
-    );
+        None if !trait_impl_contents.is_empty() => {
+            return Err(Error::new_spanned(
+                struct_ident,
+                "`trait_method` requires the trait to be named, e.g. `#[get_set(impl_trait = \"Trait\")]`",
+            ));
+        }
+        None => quote! {},
+    };
 
     Ok(quote! {
         #input
 
-        impl #struct_ident {
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
             #impl_contents
         }
+
+        #trait_impl
+
+        #(#shared_trait_impls)*
+
+        #field_keys
+
+        #field_enum
+
+        #reflect
+
+        #visit
+
+        #diff
+
+        #dirty
+
+        #observable
+
+        #history
+
+        #events
+
+        #versioned
+
+        #cache
+
+        #projections
+
+        #index_error
+
+        #flattenable
+
+        #flattened
     })
 }
 
+fn expand_func(
+    index: usize,
+    field_ident: &Ident,
+    field_props: &FieldProps,
+    func_props: &FuncProps,
+    hooks: &WriteHooks,
+) -> TokenStream {
+    let ty = &field_props.ty;
+    let FuncProps {
+        kind,
+        name,
+        inline,
+        ty: ty_override,
+        ..
+    } = func_props;
+
+    let (sig, body) = match kind {
+        Kind::Setr => {
+            let new_val_name = format_ident!("new_{}", field_ident);
+            let setter_body =
+                hooks.setter_body(index, field_ident, field_props, &new_val_name, func_props);
+            let ret = func_props.setter_ret();
+
+            let (sig, body) = match ty_override {
+                Some(ty_override) => (
+                    quote! { (&mut self, #new_val_name: #ty_override) #ret },
+                    quote! {
+                        let #new_val_name: #ty = ::core::convert::Into::into(#new_val_name);
+                        #setter_body
+                    },
+                ),
+                None => (quote! { (&mut self, #new_val_name: #ty) #ret }, setter_body),
+            };
+
+            (sig, body)
+        }
+        Kind::GetrRef | Kind::GetrCopy => {
+            let amp = if *kind == Kind::GetrRef && !matches!(ty, Type::Reference(_)) {
+                quote! { & }
+            } else {
+                quote! {}
+            };
+
+            // The field is coerced into the overridden type, e.g. `&String` into `&str`.
+            let sig = match ty_override {
+                Some(ty_override) => quote! { (&self) -> #ty_override },
+                None => quote! { (&self) -> #amp #ty },
+            };
+            let body = quote! { #amp self.#field_ident };
+
+            (sig, body)
+        }
+        Kind::Option(option_fn) => expand_option_fn(
+            *option_fn,
+            index,
+            field_ident,
+            field_props,
+            func_props,
+            hooks,
+        ),
+        Kind::Collection(collection_fn, container) => expand_collection_fn(
+            *collection_fn,
+            *container,
+            index,
+            field_ident,
+            func_props,
+            hooks,
+        ),
+        Kind::Map(map_fn, map) => {
+            expand_map_fn(*map_fn, *map, index, field_ident, func_props, hooks)
+        }
+        Kind::Indexed(indexed_fn) => {
+            expand_indexed_fn(*indexed_fn, index, field_ident, func_props, hooks)
+        }
+        Kind::Numeric(numeric_fn, mode) => {
+            expand_numeric_fn(*numeric_fn, *mode, index, field_ident, ty, hooks)
+        }
+        Kind::Bool(bool_fn) => expand_bool_fn(*bool_fn, index, field_ident, hooks),
+        Kind::Lens => {
+            // Non-capturing closures coerce into the `fn` pointers `FieldLens` is made of.
+            let sig = quote! { () -> FieldLens<Self, #ty> };
+            let body = quote! {
+                FieldLens::new(|s| &s.#field_ident, |s| &mut s.#field_ident)
+            };
+
+            (sig, body)
+        }
+    };
+
+    let vis = func_props.item_vis();
+
+    quote! {
+        #inline
+        #vis fn #name #sig {
+            #body
+        }
+    }
+}
+
+/// Adds a (hidden) field to the struct, after its fields have been processed.
+fn inject_field(input: &mut ItemStruct, field: TokenStream) -> Result<()> {
+    if let Fields::Named(fields_named) = &mut input.fields {
+        fields_named.named.push(Field::parse_named.parse2(field)?);
+    }
+
+    Ok(())
+}
+
 fn extract_opt_func_props(gs_flag: &Meta) -> Result<OptFuncProps> {
     let mut opt_func_props = OptFuncProps::new();
 
@@ -185,13 +752,54 @@ fn extract_opt_func_props(gs_flag: &Meta) -> Result<OptFuncProps> {
     Ok(opt_func_props)
 }
 
+/// Splits the settings specific to a preset, e.g. `item = ".."`, from the function settings,
+/// which can't be hooks.
+fn extract_preset_settings(
+    gs_flag: &Meta,
+    names: &[&str],
+) -> Result<(Vec<MetaNameValue>, OptFuncProps)> {
+    let mut settings = Vec::new();
+    let mut opt_func_props = OptFuncProps::new();
+
+    if let Meta::List(gs_flag_settings) = gs_flag {
+        for setting in
+            gs_flag_settings.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+        {
+            match setting {
+                Meta::NameValue(mnv) if names.iter().any(|name| mnv.path.is_ident(name)) => {
+                    settings.push(mnv)
+                }
+                setting => {
+                    opt_func_props =
+                        <Meta as TryInto<OptFuncProps>>::try_into(setting)?.or(opt_func_props)
+                }
+            }
+        }
+    }
+
+    // The presets drop the settings of single functions, hooks would be lost silently.
+    let preset = gs_flag
+        .path()
+        .get_ident()
+        .map(Ident::to_string)
+        .unwrap_or_default();
+    opt_func_props.reject_hooks(gs_flag, &preset)?;
+
+    Ok((settings, opt_func_props))
+}
+
 fn extract_default_func_props(gs_flags: &Punctuated<Meta, Token![,]>) -> Result<OptFuncProps> {
-    Ok(gs_flags
+    let Some(gs_flag) = gs_flags
         .iter()
         .filter(|&gs_flag| gs_flag.path().is_ident("default"))
         .last()
-        .map(extract_opt_func_props)
-        .transpose()?
-        .unwrap_or_default()
-        .remove_name())
+    else {
+        return Ok(OptFuncProps::default());
+    };
+
+    // Dropped with the other settings of single functions, hooks would be lost silently.
+    let opt_func_props = extract_opt_func_props(gs_flag)?;
+    opt_func_props.reject_hooks(gs_flag, "default")?;
+
+    Ok(opt_func_props.remove_specific())
 }
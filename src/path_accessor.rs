@@ -0,0 +1,152 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Ident, Member, Result};
+
+use crate::enums::{Kind, Target};
+use crate::hooks::WriteHooks;
+use crate::props::{FieldProps, FuncProps, OptFuncProps, PathAccessor};
+
+/// `a.b.c`
+fn place(runs: &[Member]) -> TokenStream {
+    quote! { #(.#runs)* }
+}
+
+/// `Option<&(mut) X>` of the value before the last run, hopping through every `Option` on the way.
+fn hops(runs: &[Vec<Member>], mutable: bool) -> TokenStream {
+    let as_ref = if mutable {
+        quote! { as_mut }
+    } else {
+        quote! { as_ref }
+    };
+
+    let first = place(&runs[0]);
+    let mut hops = quote! { self #first.#as_ref() };
+
+    for run in &runs[1..runs.len() - 1] {
+        let run = place(run);
+        hops = quote! { #hops.and_then(|__v| __v #run.#as_ref()) };
+    }
+
+    hops
+}
+
+/// The getter and setter reaching through `path`, a field is written when
+/// the path starts at it, and its `Option` hops make the accessors fallible.
+pub(crate) fn expand_path_accessor(
+    field_map: &[(Ident, FieldProps)],
+    path_accessor: &PathAccessor,
+    default_func_props: &OptFuncProps,
+    hooks: &WriteHooks,
+) -> Result<TokenStream> {
+    let PathAccessor {
+        name,
+        ty,
+        runs,
+        path,
+        get,
+        set,
+    } = path_accessor;
+
+    let Member::Named(first) = &runs[0][0] else {
+        return Err(Error::new_spanned(
+            path,
+            "Paths must start at a named field",
+        ));
+    };
+    let Some(index) = field_map
+        .iter()
+        .position(|(field_ident, _)| field_ident == first)
+    else {
+        return Err(Error::new_spanned(
+            path,
+            format!("`{first}` is not a field of the struct"),
+        ));
+    };
+
+    let last = place(runs.last().unwrap());
+    let optional = runs.len() > 1;
+    let mut funcs = quote! {};
+
+    let build = |opt_func_props: &OptFuncProps, kind: Kind| -> Result<FuncProps> {
+        let func_props = opt_func_props
+            .clone()
+            .or(default_func_props.clone())
+            .build(kind, name);
+
+        if func_props.target != Target::Inherent {
+            return Err(Error::new_spanned(
+                name,
+                "`trait_method` and `shared_trait` are not supported on `path`",
+            ));
+        }
+
+        Ok(func_props)
+    };
+
+    if let Some(get) = get {
+        let FuncProps {
+            vis, inline, name, ..
+        } = build(get, Kind::GetrRef)?;
+
+        let (ret, body) = if optional {
+            let hops = hops(runs, false);
+
+            (
+                quote! { ::core::option::Option<&#ty> },
+                quote! { #hops.map(|__v| &__v #last) },
+            )
+        } else {
+            (quote! { &#ty }, quote! { &self #last })
+        };
+
+        funcs.extend(quote! {
+            #inline
+            #vis fn #name(&self) -> #ret {
+                #body
+            }
+        });
+    }
+
+    if let Some(set) = set {
+        let FuncProps {
+            vis, inline, name, ..
+        } = build(set, Kind::Setr)?;
+        let new_val = format_ident!("new_{}", path_accessor.name);
+        let after_write = hooks.after_write(index, first);
+
+        let (ret, body) = if optional {
+            let hops = hops(runs, true);
+
+            (
+                quote! { -> bool },
+                quote! {
+                    match #hops {
+                        ::core::option::Option::Some(__v) => {
+                            __v #last = #new_val;
+                            #after_write
+                            true
+                        }
+                        ::core::option::Option::None => false,
+                    }
+                },
+            )
+        } else {
+            (
+                quote! {},
+                quote! {
+                    self #last = #new_val;
+                    #after_write
+                },
+            )
+        };
+
+        funcs.extend(quote! {
+            #inline
+            #vis fn #name(&mut self, #new_val: #ty) #ret {
+                #body
+            }
+        });
+    }
+
+    Ok(funcs)
+}
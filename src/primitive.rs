@@ -0,0 +1,173 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Ident, MetaNameValue, Result, Type};
+
+use crate::enums::{BoolFn, NumericFn, NumericMode};
+use crate::hooks::WriteHooks;
+use crate::props::expect_lit_str;
+
+const INTEGERS: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+const FLOATS: [&str; 2] = ["f32", "f64"];
+
+fn primitive_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.get_ident().map(Ident::to_string)
+        }
+        _ => None,
+    }
+}
+
+/// The overflow mode of a `numeric` field, from the `mode = ".."` setting.
+pub(crate) fn numeric_mode(ty: &Type, settings: &[MetaNameValue]) -> Result<NumericMode> {
+    let mut mode = NumericMode::default();
+
+    for setting in settings {
+        let name = expect_lit_str(setting.value.clone(), "mode = \"saturating\"")?;
+        mode = NumericMode::from_name(&name.value()).ok_or_else(|| {
+            Error::new_spanned(
+                &name,
+                "Valid modes are `wrapping`, `saturating` and `checked`",
+            )
+        })?;
+    }
+
+    match primitive_name(ty) {
+        Some(name) if INTEGERS.contains(&name.as_str()) => Ok(mode),
+        Some(name) if FLOATS.contains(&name.as_str()) && mode == NumericMode::Plain => Ok(mode),
+        Some(name) if FLOATS.contains(&name.as_str()) => Err(Error::new_spanned(
+            ty,
+            "Floats only support the plain `numeric` mode",
+        )),
+        _ => Err(Error::new_spanned(
+            ty,
+            "`numeric` requires a primitive integer or float field",
+        )),
+    }
+}
+
+pub(crate) fn check_bool(ty: &Type) -> Result<()> {
+    match primitive_name(ty) {
+        Some(name) if name == "bool" => Ok(()),
+        _ => Err(Error::new_spanned(ty, "`bool` requires a `bool` field")),
+    }
+}
+
+/// The signature and body of a function of the `numeric` preset.
+pub(crate) fn expand_numeric_fn(
+    numeric_fn: NumericFn,
+    mode: NumericMode,
+    index: usize,
+    field_ident: &Ident,
+    ty: &Type,
+    hooks: &WriteHooks,
+) -> (TokenStream, TokenStream) {
+    let after_write = hooks.after_write(index, field_ident);
+
+    let (arg, value) = match numeric_fn {
+        NumericFn::Incr | NumericFn::Decr => (quote! {}, quote! { (1 as #ty) }),
+        NumericFn::Add | NumericFn::Sub => (quote! { , value: #ty }, quote! { value }),
+    };
+    let sub = matches!(numeric_fn, NumericFn::Decr | NumericFn::Sub);
+
+    let (ret, body) = match mode {
+        NumericMode::Plain => {
+            let op = if sub {
+                quote! { -= }
+            } else {
+                quote! { += }
+            };
+
+            (quote! {}, quote! { self.#field_ident #op #value; })
+        }
+        NumericMode::Wrapping => {
+            let op = if sub {
+                quote! { wrapping_sub }
+            } else {
+                quote! { wrapping_add }
+            };
+
+            (
+                quote! {},
+                quote! { self.#field_ident = self.#field_ident.#op(#value); },
+            )
+        }
+        NumericMode::Saturating => {
+            let op = if sub {
+                quote! { saturating_sub }
+            } else {
+                quote! { saturating_add }
+            };
+
+            (
+                quote! {},
+                quote! { self.#field_ident = self.#field_ident.#op(#value); },
+            )
+        }
+        NumericMode::Checked => {
+            let op = if sub {
+                quote! { checked_sub }
+            } else {
+                quote! { checked_add }
+            };
+
+            (
+                quote! { -> bool },
+                quote! {
+                    let ::core::option::Option::Some(new) = self.#field_ident.#op(#value) else {
+                        return false;
+                    };
+                    self.#field_ident = new;
+                },
+            )
+        }
+    };
+
+    let tail = (mode == NumericMode::Checked).then(|| quote! { true });
+
+    (
+        quote! { (&mut self #arg) #ret },
+        quote! {
+            #body
+            #after_write
+            #tail
+        },
+    )
+}
+
+/// The signature and body of a function of the `bool` preset.
+pub(crate) fn expand_bool_fn(
+    bool_fn: BoolFn,
+    index: usize,
+    field_ident: &Ident,
+    hooks: &WriteHooks,
+) -> (TokenStream, TokenStream) {
+    let after_write = hooks.after_write(index, field_ident);
+
+    match bool_fn {
+        BoolFn::Is => (quote! { (&self) -> bool }, quote! { self.#field_ident }),
+        BoolFn::Toggle => (
+            quote! { (&mut self) },
+            quote! {
+                self.#field_ident = !self.#field_ident;
+                #after_write
+            },
+        ),
+        // Only an actual change counts as a write.
+        BoolFn::Enable | BoolFn::Disable => {
+            let value = bool_fn == BoolFn::Enable;
+
+            (
+                quote! { (&mut self) },
+                quote! {
+                    if self.#field_ident != #value {
+                        self.#field_ident = #value;
+                        #after_write
+                    }
+                },
+            )
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Error, Ident, ItemStruct, Result};
+
+use crate::enums::Projection;
+use crate::props::FieldProps;
+
+/// The `AsRef`, `AsMut`, `Borrow`, `Deref`, `DerefMut` and `Index` impls forwarding to fields.
+pub(crate) fn expand_projections(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+) -> Result<TokenStream> {
+    let struct_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut deref_field: Option<&Ident> = None;
+    let mut impls = quote! {};
+
+    for (field_ident, field_props) in field_map {
+        let ty = &field_props.ty;
+
+        let derefs = field_props
+            .projections
+            .iter()
+            .any(|projection| matches!(projection, Projection::Deref | Projection::DerefMut));
+
+        if derefs {
+            if let Some(other) = deref_field {
+                return Err(Error::new_spanned(
+                    field_ident,
+                    format!("`deref` can only be placed on one field, it is already placed on `{other}`"),
+                ));
+            }
+
+            deref_field = Some(field_ident);
+
+            impls.extend(quote! {
+                impl #impl_generics ::core::ops::Deref for #struct_ident #ty_generics #where_clause {
+                    type Target = #ty;
+
+                    fn deref(&self) -> &Self::Target {
+                        &self.#field_ident
+                    }
+                }
+            });
+        }
+
+        for projection in &field_props.projections {
+            impls.extend(match projection {
+                Projection::AsRef => quote! {
+                    impl #impl_generics ::core::convert::AsRef<#ty> for #struct_ident #ty_generics #where_clause {
+                        fn as_ref(&self) -> &#ty {
+                            &self.#field_ident
+                        }
+                    }
+                },
+                Projection::AsMut => quote! {
+                    impl #impl_generics ::core::convert::AsMut<#ty> for #struct_ident #ty_generics #where_clause {
+                        fn as_mut(&mut self) -> &mut #ty {
+                            &mut self.#field_ident
+                        }
+                    }
+                },
+                Projection::Borrow => quote! {
+                    impl #impl_generics ::core::borrow::Borrow<#ty> for #struct_ident #ty_generics #where_clause {
+                        fn borrow(&self) -> &#ty {
+                            &self.#field_ident
+                        }
+                    }
+                },
+                Projection::Deref => quote! {},
+                Projection::DerefMut => quote! {
+                    impl #impl_generics ::core::ops::DerefMut for #struct_ident #ty_generics #where_clause {
+                        fn deref_mut(&mut self) -> &mut Self::Target {
+                            &mut self.#field_ident
+                        }
+                    }
+                },
+                Projection::Index => {
+                    // Indexed by whatever indexes the field.
+                    let mut generics = input.generics.clone();
+                    generics.params.push(parse_quote! { __I });
+                    generics
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote! { #ty: ::core::ops::Index<__I> });
+                    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+                    quote! {
+                        impl #impl_generics ::core::ops::Index<__I> for #struct_ident #ty_generics #where_clause {
+                            type Output = <#ty as ::core::ops::Index<__I>>::Output;
+
+                            fn index(&self, index: __I) -> &Self::Output {
+                                &self.#field_ident[index]
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(impls)
+}
@@ -4,9 +4,15 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::enums::{Inline, Kind};
+use crate::enums::{Inline, Kind, OptionFn, Projection, Target};
 use proc_macro2::TokenStream;
-use syn::{Error, Expr, ExprLit, Ident, Lit, Meta, Type, Visibility};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, Expr, ExprLit, Ident, Lit, LitStr, Member, Meta, MetaList, MetaNameValue, Path,
+    Signature, Token, Type, Visibility,
+};
 
 #[derive(Clone, Debug)]
 pub(crate) struct FuncProps {
@@ -15,20 +21,30 @@ pub(crate) struct FuncProps {
     // Functions must be unique on their name, that is why equally nammed FuncProps compare equal.
     // Global (always default name) functions collide with a default name getter/setter with specific settings,
     // the one that is in the HashSet first wins (in our case the global functions always lose).
+    // Names only collide within the same `impl` block, so the target is part of the identity too.
     pub(crate) name: Ident,
+    pub(crate) target: Target,
     pub(crate) inline: Inline,
+    // Overrides the field type in the signature, e.g. `&str` for a `String` field.
+    pub(crate) ty: Option<Type>,
+    // Setter hooks, called with the old and new values. `before` can veto the write,
+    // by returning `false`, or an `Err(error)` when the error type is given.
+    pub(crate) before: Option<Path>,
+    pub(crate) after: Option<Path>,
+    pub(crate) error: Option<Type>,
 }
 
 impl PartialEq for FuncProps {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name
+        self.name == other.name && self.target == other.target
     }
 }
 impl Eq for FuncProps {}
 
 impl Hash for FuncProps {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.name.hash(state)
+        self.name.hash(state);
+        self.target.hash(state);
     }
 }
 
@@ -63,6 +79,13 @@ pub(crate) struct OptFuncProps {
     pub(crate) name: Option<Ident>,
     pub(crate) inline: Option<Inline>,
     pub(crate) vis: Option<Visibility>,
+    pub(crate) trait_method: Option<Ident>,
+    // `Some(None)` is the bare `shared_trait` flag, which uses the trait declared by `shared_traits!`.
+    pub(crate) shared_trait: Option<Option<Path>>,
+    pub(crate) ty: Option<Type>,
+    pub(crate) before: Option<Path>,
+    pub(crate) after: Option<Path>,
+    pub(crate) error: Option<Type>,
 }
 
 impl Default for OptFuncProps {
@@ -77,11 +100,28 @@ impl OptFuncProps {
             vis: None,
             name: None,
             inline: None,
+            trait_method: None,
+            shared_trait: None,
+            ty: None,
+            before: None,
+            after: None,
+            error: None,
         }
     }
 
-    pub(crate) fn remove_name(self) -> Self {
-        Self { name: None, ..self }
+    /// Removes the settings that only make sense for a single function
+    /// (`rename`, `trait_method`, `shared_trait`, `ty` and the setter hooks).
+    pub(crate) fn remove_specific(self) -> Self {
+        Self {
+            name: None,
+            trait_method: None,
+            shared_trait: None,
+            ty: None,
+            before: None,
+            after: None,
+            error: None,
+            ..self
+        }
     }
 
     pub(crate) fn or(self, other: OptFuncProps) -> Self {
@@ -89,23 +129,71 @@ impl OptFuncProps {
             vis: self.vis.or(other.vis),
             name: self.name.or(other.name),
             inline: self.inline.or(other.inline),
+            trait_method: self.trait_method.or(other.trait_method),
+            shared_trait: self.shared_trait.or(other.shared_trait),
+            ty: self.ty.or(other.ty),
+            before: self.before.or(other.before),
+            after: self.after.or(other.after),
+            error: self.error.or(other.error),
         }
     }
 
     pub(crate) fn build(self, kind: Kind, field: &Ident) -> FuncProps {
+        let (name, target) = match (self.trait_method, self.shared_trait) {
+            (Some(trait_method), _) => (trait_method, Target::ImplTrait),
+            // The method name is fixed by the trait declared in `shared_traits!`.
+            (None, Some(shared_trait)) => (
+                kind.into_ident(field),
+                Target::SharedTrait(
+                    shared_trait.unwrap_or_else(|| kind.shared_trait_ident(field).into()),
+                ),
+            ),
+            (None, None) => (
+                self.name.unwrap_or(kind.into_ident(field)),
+                Target::Inherent,
+            ),
+        };
+
         FuncProps {
             kind, // Trivially copyable
             inline: self.inline.unwrap_or_default(),
             vis: self.vis.unwrap_or(Visibility::Inherited),
-            name: self.name.unwrap_or(kind.into_ident(field)),
+            name,
+            target,
+            ty: self.ty,
+            before: self.before,
+            after: self.after,
+            error: self.error,
         }
     }
 
     pub(crate) fn build_with_default_name(self, kind: Kind, field: &Ident) -> FuncProps {
-        FuncProps {
-            name: kind.into_ident(field),
-            ..self.build(kind, field)
+        self.remove_specific().build(kind, field)
+    }
+
+    /// Errors on the setter hooks, for the functions that would ignore them.
+    pub(crate) fn reject_hooks(&self, tokens: impl ToTokens, what: &str) -> Result<(), Error> {
+        if self.before.is_some() || self.after.is_some() || self.error.is_some() {
+            return Err(Error::new_spanned(
+                tokens,
+                format!("`before`, `after` and `error` are not supported on `{what}`"),
+            ));
         }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn expect_lit_str(value: Expr, usage: &str) -> Result<LitStr, Error> {
+    match value {
+        Expr::Lit(ExprLit {
+            attrs: _attrs,
+            lit: Lit::Str(str),
+        }) => Ok(str),
+        _ => Err(Error::new_spanned(
+            value,
+            format!("Valid gsflag setting is `{usage}`"),
+        )),
     }
 }
 
@@ -115,16 +203,8 @@ impl TryFrom<Meta> for OptFuncProps {
     fn try_from(setting: Meta) -> Result<Self, Self::Error> {
         match setting {
             Meta::NameValue(mnv) if mnv.path.is_ident("rename") => {
-                let rename = match mnv.value {
-                    Expr::Lit(ExprLit {
-                        attrs: _attrs,
-                        lit: Lit::Str(str),
-                    }) => Ok(Ident::new(str.value().as_str(), str.span())),
-                    _ => Err(Error::new_spanned(
-                        mnv.value,
-                        "Valid gsflag setting is `rename = \"name\"`",
-                    )),
-                }?;
+                let rename = expect_lit_str(mnv.value, "rename = \"name\"")?;
+                let rename = Ident::new(rename.value().as_str(), rename.span());
 
                 Ok(OptFuncProps {
                     name: Some(rename),
@@ -155,22 +235,66 @@ impl TryFrom<Meta> for OptFuncProps {
                 })
             }
             Meta::NameValue(mnv) if mnv.path.is_ident("vis") => {
-                let vis = match mnv.value {
-                    Expr::Lit(ExprLit {
-                        attrs: _attrs,
-                        lit: Lit::Str(str),
-                    }) => Ok(syn::parse2::<Visibility>(str.parse::<TokenStream>()?)?),
-                    _ => Err(Error::new_spanned(
-                        mnv.value,
-                        "Valid gsflag setting is `vis = \"pub(crate)\"`",
-                    )),
-                }?;
+                let vis = expect_lit_str(mnv.value, "vis = \"pub(crate)\"")?;
+                let vis = syn::parse2::<Visibility>(vis.parse::<TokenStream>()?)?;
 
                 Ok(OptFuncProps {
                     vis: Some(vis),
                     ..Default::default()
                 })
             }
+            Meta::NameValue(mnv) if mnv.path.is_ident("trait_method") => {
+                let trait_method = expect_lit_str(mnv.value, "trait_method = \"name\"")?;
+
+                Ok(OptFuncProps {
+                    trait_method: Some(trait_method.parse()?),
+                    ..Default::default()
+                })
+            }
+            Meta::Path(path) if path.is_ident("shared_trait") => Ok(OptFuncProps {
+                shared_trait: Some(None),
+                ..Default::default()
+            }),
+            Meta::NameValue(mnv) if mnv.path.is_ident("shared_trait") => {
+                let shared_trait = expect_lit_str(mnv.value, "shared_trait = \"HasName\"")?;
+
+                Ok(OptFuncProps {
+                    shared_trait: Some(Some(shared_trait.parse()?)),
+                    ..Default::default()
+                })
+            }
+            Meta::NameValue(mnv) if mnv.path.is_ident("before") => {
+                let before = expect_lit_str(mnv.value, "before = \"Self::on_before_x\"")?;
+
+                Ok(OptFuncProps {
+                    before: Some(before.parse()?),
+                    ..Default::default()
+                })
+            }
+            Meta::NameValue(mnv) if mnv.path.is_ident("after") => {
+                let after = expect_lit_str(mnv.value, "after = \"Self::on_after_x\"")?;
+
+                Ok(OptFuncProps {
+                    after: Some(after.parse()?),
+                    ..Default::default()
+                })
+            }
+            Meta::NameValue(mnv) if mnv.path.is_ident("error") => {
+                let error = expect_lit_str(mnv.value, "error = \"MyError\"")?;
+
+                Ok(OptFuncProps {
+                    error: Some(error.parse()?),
+                    ..Default::default()
+                })
+            }
+            Meta::NameValue(mnv) if mnv.path.is_ident("ty") => {
+                let ty = expect_lit_str(mnv.value, "ty = \"&str\"")?;
+
+                Ok(OptFuncProps {
+                    ty: Some(ty.parse()?),
+                    ..Default::default()
+                })
+            }
             _ => Err(Error::new_spanned(
                 setting,
                 "Invalid usage, see `README.md`",
@@ -179,8 +303,688 @@ impl TryFrom<Meta> for OptFuncProps {
     }
 }
 
+impl FuncProps {
+    /// The return type of a setter whose `before` hook can veto the write.
+    pub(crate) fn setter_ret(&self) -> Option<TokenStream> {
+        match (&self.before, &self.error) {
+            (Some(_), Some(error)) => Some(quote! { -> ::core::result::Result<(), #error> }),
+            (Some(_), None) => Some(quote! { -> bool }),
+            (None, _) => None,
+        }
+    }
+
+    /// The visibility to write on the function, none for trait items, which inherit the
+    /// visibility of the trait.
+    pub(crate) fn item_vis(&self) -> Option<&Visibility> {
+        match self.target {
+            Target::Inherent => Some(&self.vis),
+            Target::ImplTrait | Target::SharedTrait(_) => None,
+        }
+    }
+
+    /// Whether the function can be called wherever `vis` reaches. Trait methods are as visible as their trait.
+    pub(crate) fn reaches(&self, vis: &Visibility) -> bool {
+        self.target != Target::Inherent || vis_at_least(&self.vis, vis)
+    }
+
+    /// The path used to call this function, e.g. `<Self>::set_name` or `<Self as Named>::set_name`.
+    pub(crate) fn call_path(
+        &self,
+        self_ty: &TokenStream,
+        impl_trait: Option<&Path>,
+    ) -> TokenStream {
+        let name = &self.name;
+
+        match &self.target {
+            Target::Inherent => quote! { <#self_ty>::#name },
+            Target::ImplTrait => quote! { <#self_ty as #impl_trait>::#name },
+            Target::SharedTrait(path) => quote! { <#self_ty as #path>::#name },
+        }
+    }
+}
+
+/// Whether `vis` reaches at least as far as `other`, as far as the tokens tell.
+/// Distinct restricted paths are never assumed to contain each other.
+pub(crate) fn vis_at_least(vis: &Visibility, other: &Visibility) -> bool {
+    fn rank(vis: &Visibility) -> u8 {
+        match vis {
+            Visibility::Public(_) => 3,
+            Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => 2,
+            Visibility::Restricted(restricted) if restricted.path.is_ident("self") => 0,
+            Visibility::Restricted(_) => 1,
+            Visibility::Inherited => 0,
+        }
+    }
+
+    match (vis, other) {
+        (Visibility::Restricted(vis_restricted), Visibility::Restricted(other_restricted))
+            if rank(vis) == 1 && rank(other) == 1 =>
+        {
+            vis_restricted.path.to_token_stream().to_string()
+                == other_restricted.path.to_token_stream().to_string()
+        }
+        _ => rank(vis) >= rank(other),
+    }
+}
+
+/// A method of the field exposed on the struct, e.g. `pub fn len(&self) -> usize`.
+pub(crate) struct Delegate {
+    pub(crate) vis: Visibility,
+    pub(crate) inline: Inline,
+    pub(crate) sig: Signature,
+}
+
+impl Parse for Delegate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            vis: input.parse()?,
+            inline: Inline::None,
+            sig: input.parse()?,
+        })
+    }
+}
+
+/// `#[gsflags(flatten)]`, forwarding the accessors of a `#[get_set(flattenable)]` field.
+pub(crate) struct Flatten {
+    // Inserted into the forwarded names, the field name by default.
+    pub(crate) prefix: Option<String>,
+    // The settings of the forwarding accessors (`vis`, `inline`).
+    pub(crate) opt_func_props: OptFuncProps,
+}
+
+impl TryFrom<&Meta> for Flatten {
+    type Error = Error;
+
+    fn try_from(gsflag: &Meta) -> Result<Self, Self::Error> {
+        let mut flatten = Flatten {
+            prefix: None,
+            opt_func_props: OptFuncProps::new(),
+        };
+
+        if let Meta::List(list) = gsflag {
+            for setting in list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+                match setting {
+                    Meta::NameValue(mnv) if mnv.path.is_ident("prefix") => {
+                        let prefix = expect_lit_str(mnv.value, "prefix = \"inner\"")?;
+                        flatten.prefix = Some(prefix.value());
+                    }
+                    _ if ["vis", "inline", "inline_always", "inline_never", "noinline"]
+                        .iter()
+                        .any(|name| setting.path().is_ident(name)) =>
+                    {
+                        flatten.opt_func_props =
+                            OptFuncProps::try_from(setting)?.or(flatten.opt_func_props);
+                    }
+                    _ => return Err(Error::new_spanned(
+                        setting,
+                        "Valid settings are `flatten(prefix = \"inner\", vis = \"pub\", inline)`",
+                    )),
+                }
+            }
+        }
+
+        Ok(flatten)
+    }
+}
+
 pub(crate) struct FieldProps {
     pub(crate) ty: Type,
     pub(crate) all_skip: bool,
+    // Setters record the replaced value, see `#[get_set(history)]`.
+    pub(crate) history: bool,
     pub(crate) props: HashSet<FuncProps>,
+    pub(crate) delegates: Vec<Delegate>,
+    pub(crate) projections: Vec<Projection>,
+    pub(crate) flatten: Option<Flatten>,
+}
+
+impl FieldProps {
+    /// Whether the field has a variant in `ExampleField` and the other per-field enums,
+    /// which it does unless it is skipped without a getter or setter of its own.
+    pub(crate) fn is_listed(&self) -> bool {
+        !self.all_skip || self.has_getter() || self.has_setter()
+    }
+
+    pub(crate) fn has_getter(&self) -> bool {
+        self.props
+            .iter()
+            .any(|props| matches!(props.kind, Kind::GetrRef | Kind::GetrCopy))
+    }
+
+    pub(crate) fn has_setter(&self) -> bool {
+        self.props.iter().any(|props| props.kind == Kind::Setr) || self.option_setter().is_some()
+    }
+
+    /// Whether a getter can be called wherever `vis` reaches.
+    pub(crate) fn has_getter_at(&self, vis: &Visibility) -> bool {
+        self.props
+            .iter()
+            .any(|props| matches!(props.kind, Kind::GetrRef | Kind::GetrCopy) && props.reaches(vis))
+    }
+
+    /// `setter()`, limited to the setters that can be called wherever `vis` reaches.
+    pub(crate) fn setter_at(&self, vis: &Visibility) -> Option<&FuncProps> {
+        self.props
+            .iter()
+            .filter(|props| props.kind == Kind::Setr && props.ty.is_none() && props.reaches(vis))
+            .min_by_key(|props| props.target != Target::Inherent)
+    }
+
+    /// `set_x` of the `option` preset, which writes the field like a setter.
+    pub(crate) fn option_setter(&self) -> Option<&FuncProps> {
+        self.props
+            .iter()
+            .filter(|props| props.kind == Kind::Option(OptionFn::Set))
+            .min_by_key(|props| props.target != Target::Inherent)
+    }
+
+    /// `clear_x` of the `option` preset.
+    pub(crate) fn option_clearer(&self) -> Option<&FuncProps> {
+        self.props
+            .iter()
+            .filter(|props| props.kind == Kind::Option(OptionFn::Clear))
+            .min_by_key(|props| props.target != Target::Inherent)
+    }
+
+    /// A setter taking the field type, inherent setters are preferred.
+    pub(crate) fn setter(&self) -> Option<&FuncProps> {
+        self.props
+            .iter()
+            .filter(|props| props.kind == Kind::Setr && props.ty.is_none())
+            .min_by_key(|props| props.target != Target::Inherent)
+    }
+}
+
+pub(crate) struct TrackDirty {
+    // Setters only mark their field when the new value differs from the old one.
+    pub(crate) skip_unchanged: bool,
+}
+
+pub(crate) struct Observable {
+    // Events carry the old and new values of the field.
+    pub(crate) values: bool,
+    pub(crate) name: Option<Ident>,
+}
+
+pub(crate) struct History {
+    // The number of undoable writes kept, unbounded by default.
+    pub(crate) capacity: Option<usize>,
+}
+
+pub(crate) struct Events {
+    // Called by every generated setter with the event for its write.
+    pub(crate) sink: Option<Path>,
+    pub(crate) name: Option<Ident>,
+}
+
+pub(crate) struct Versioned {
+    // Also keeps a version per field.
+    pub(crate) per_field: bool,
+    // The counter, the struct's own `version` field if it has one.
+    pub(crate) field: Ident,
+}
+
+impl Versioned {
+    fn new() -> Self {
+        Self {
+            per_field: false,
+            field: format_ident!("__version"),
+        }
+    }
+}
+
+pub(crate) enum ComputedBody {
+    Expr(Expr),
+    // Called with `&self`.
+    Func(Path),
+}
+
+pub(crate) struct Computed {
+    pub(crate) name: Ident,
+    pub(crate) ty: Type,
+    pub(crate) body: ComputedBody,
+    pub(crate) doc: Option<LitStr>,
+    // The getter settings (`rename`, `vis`, `inline`, ...), which default to the struct's `default(...)`.
+    pub(crate) opt_func_props: OptFuncProps,
+}
+
+impl TryFrom<&MetaList> for Computed {
+    type Error = Error;
+
+    fn try_from(list: &MetaList) -> Result<Self, Self::Error> {
+        let usage = "computed(name = \"full_name\", ty = \"String\", expr = \"...\" | func = \"Self::full_name\")";
+        let mut name = None;
+        let mut ty = None;
+        let mut body = None;
+        let mut doc = None;
+        let mut opt_func_props = OptFuncProps::new();
+
+        for setting in list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+            match setting {
+                Meta::NameValue(mnv) if mnv.path.is_ident("name") => {
+                    name = Some(expect_lit_str(mnv.value, "name = \"full_name\"")?.parse()?);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("ty") => {
+                    ty = Some(expect_lit_str(mnv.value, "ty = \"String\"")?.parse()?);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("expr") => {
+                    let expr = expect_lit_str(mnv.value, "expr = \"self.a + self.b\"")?;
+                    body = Some(ComputedBody::Expr(expr.parse()?));
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("func") => {
+                    let func = expect_lit_str(mnv.value, "func = \"Self::full_name\"")?;
+                    body = Some(ComputedBody::Func(func.parse()?));
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("doc") => {
+                    doc = Some(expect_lit_str(mnv.value, "doc = \"...\"")?);
+                }
+                setting => opt_func_props = OptFuncProps::try_from(setting)?.or(opt_func_props),
+            }
+        }
+
+        opt_func_props.reject_hooks(list, "computed")?;
+
+        match (name, ty, body) {
+            (Some(name), Some(ty), Some(body)) => Ok(Self {
+                name,
+                ty,
+                body,
+                doc,
+                opt_func_props,
+            }),
+            _ => Err(Error::new_spanned(
+                list,
+                format!("Valid usage is `{usage}`"),
+            )),
+        }
+    }
+}
+
+pub(crate) struct Cached {
+    pub(crate) name: Ident,
+    pub(crate) ty: Type,
+    // Called with `&self` to fill the cache.
+    pub(crate) compute: Path,
+    // The fields whose generated mutators invalidate the cache.
+    pub(crate) depends_on: Vec<Ident>,
+    pub(crate) doc: Option<LitStr>,
+    // The getter settings (`rename`, `vis`, `inline`, ...), which default to the struct's `default(...)`.
+    pub(crate) opt_func_props: OptFuncProps,
+}
+
+impl TryFrom<&MetaList> for Cached {
+    type Error = Error;
+
+    fn try_from(list: &MetaList) -> Result<Self, Self::Error> {
+        let usage = "cached(name = \"area\", ty = \"f64\", compute = \"Self::compute_area\", depends_on(width, height))";
+        let mut name = None;
+        let mut ty = None;
+        let mut compute = None;
+        let mut depends_on = Vec::new();
+        let mut doc = None;
+        let mut opt_func_props = OptFuncProps::new();
+
+        for setting in list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+            match setting {
+                Meta::NameValue(mnv) if mnv.path.is_ident("name") => {
+                    name = Some(expect_lit_str(mnv.value, "name = \"area\"")?.parse()?);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("ty") => {
+                    ty = Some(expect_lit_str(mnv.value, "ty = \"f64\"")?.parse()?);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("compute") => {
+                    let compute_fn = expect_lit_str(mnv.value, "compute = \"Self::compute_area\"")?;
+                    compute = Some(compute_fn.parse()?);
+                }
+                Meta::List(list) if list.path.is_ident("depends_on") => {
+                    depends_on.extend(
+                        list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?,
+                    );
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("doc") => {
+                    doc = Some(expect_lit_str(mnv.value, "doc = \"...\"")?);
+                }
+                setting => opt_func_props = OptFuncProps::try_from(setting)?.or(opt_func_props),
+            }
+        }
+
+        opt_func_props.reject_hooks(list, "cached")?;
+
+        match (name, ty, compute) {
+            (Some(name), Some(ty), Some(compute)) => Ok(Self {
+                name,
+                ty,
+                compute,
+                depends_on,
+                doc,
+                opt_func_props,
+            }),
+            _ => Err(Error::new_spanned(
+                list,
+                format!("Valid usage is `{usage}`"),
+            )),
+        }
+    }
+}
+
+/// `path(name = "max_limit", path = "inner?.limits.max", ty = "u32", get, set)`.
+pub(crate) struct PathAccessor {
+    pub(crate) name: Ident,
+    pub(crate) ty: Type,
+    // The fields reached through, the `Option` hops (`?`) split it into runs.
+    pub(crate) runs: Vec<Vec<Member>>,
+    pub(crate) path: LitStr,
+    // The `get` and `set` settings, which default to the struct's `default(...)`.
+    pub(crate) get: Option<OptFuncProps>,
+    pub(crate) set: Option<OptFuncProps>,
+}
+
+impl TryFrom<&MetaList> for PathAccessor {
+    type Error = Error;
+
+    fn try_from(list: &MetaList) -> Result<Self, Self::Error> {
+        let usage =
+            "path(name = \"max_limit\", path = \"inner.limits.max\", ty = \"u32\", get, set)";
+        let mut name = None;
+        let mut ty = None;
+        let mut path = None;
+        let mut get = None;
+        let mut set = None;
+
+        for setting in list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+            match setting {
+                Meta::NameValue(mnv) if mnv.path.is_ident("name") => {
+                    name = Some(expect_lit_str(mnv.value, "name = \"max_limit\"")?.parse()?);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("ty") => {
+                    ty = Some(expect_lit_str(mnv.value, "ty = \"u32\"")?.parse()?);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("path") => {
+                    path = Some(expect_lit_str(mnv.value, "path = \"inner.limits.max\"")?);
+                }
+                _ if setting.path().is_ident("get") || setting.path().is_ident("set") => {
+                    let mut opt_func_props = OptFuncProps::new();
+
+                    if let Meta::List(settings) = &setting {
+                        for func_setting in settings
+                            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                        {
+                            opt_func_props =
+                                OptFuncProps::try_from(func_setting)?.or(opt_func_props);
+                        }
+                    }
+
+                    opt_func_props.reject_hooks(&setting, "path")?;
+
+                    if setting.path().is_ident("get") {
+                        get = Some(opt_func_props);
+                    } else {
+                        set = Some(opt_func_props);
+                    }
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        setting,
+                        format!("Valid usage is `{usage}`"),
+                    ))
+                }
+            }
+        }
+
+        let (Some(name), Some(ty), Some(path)) = (name, ty, path) else {
+            return Err(Error::new_spanned(
+                list,
+                format!("Valid usage is `{usage}`"),
+            ));
+        };
+
+        let mut runs = vec![Vec::new()];
+
+        for segment in path.value().split('.') {
+            let (segment, hop) = match segment.strip_suffix('?') {
+                Some(segment) => (segment, true),
+                None => (segment, false),
+            };
+
+            let member = syn::parse_str::<Member>(segment).map_err(|_| {
+                Error::new_spanned(&path, format!("`{segment}` is not a field name"))
+            })?;
+            runs.last_mut().unwrap().push(member);
+
+            if hop {
+                runs.push(Vec::new());
+            }
+        }
+
+        if runs.last().unwrap().is_empty() {
+            return Err(Error::new_spanned(
+                &path,
+                "A path cannot end with `?`, the accessors return the `Option` for it",
+            ));
+        }
+
+        Ok(Self {
+            name,
+            ty,
+            runs,
+            path,
+            get,
+            set,
+        })
+    }
+}
+
+pub(crate) struct StructProps {
+    pub(crate) impl_trait: Option<Path>,
+    pub(crate) field_keys: bool,
+    pub(crate) field_enum: bool,
+    pub(crate) reflect: bool,
+    pub(crate) visit: bool,
+    pub(crate) diff: bool,
+    pub(crate) track_dirty: Option<TrackDirty>,
+    pub(crate) observable: Option<Observable>,
+    pub(crate) history: Option<History>,
+    pub(crate) events: Option<Events>,
+    pub(crate) versioned: Option<Versioned>,
+    pub(crate) computed: Vec<Computed>,
+    pub(crate) cached: Vec<Cached>,
+    pub(crate) flattenable: bool,
+    pub(crate) paths: Vec<PathAccessor>,
+}
+
+impl StructProps {
+    pub(crate) fn new() -> Self {
+        Self {
+            impl_trait: None,
+            field_keys: false,
+            field_enum: false,
+            reflect: false,
+            visit: false,
+            diff: false,
+            track_dirty: None,
+            observable: None,
+            history: None,
+            events: None,
+            versioned: None,
+            computed: Vec::new(),
+            cached: Vec::new(),
+            flattenable: false,
+            paths: Vec::new(),
+        }
+    }
+
+    /// Consumes a struct-wide setting, returns `false` if `gs_flag` is not a struct-wide setting.
+    pub(crate) fn try_insert(&mut self, gs_flag: &Meta) -> Result<bool, Error> {
+        match gs_flag {
+            Meta::NameValue(mnv) if mnv.path.is_ident("impl_trait") => {
+                let impl_trait = expect_lit_str(mnv.value.clone(), "impl_trait = \"Trait\"")?;
+                self.impl_trait = Some(impl_trait.parse()?);
+            }
+            Meta::Path(path) if path.is_ident("field_keys") => self.field_keys = true,
+            Meta::Path(path) if path.is_ident("field_enum") => self.field_enum = true,
+            Meta::Path(path) if path.is_ident("reflect") => self.reflect = true,
+            Meta::Path(path) if path.is_ident("visit") => self.visit = true,
+            Meta::Path(path) if path.is_ident("diff") => self.diff = true,
+            Meta::Path(path) if path.is_ident("flattenable") => self.flattenable = true,
+            Meta::Path(path) if path.is_ident("track_dirty") => {
+                self.track_dirty = Some(TrackDirty {
+                    skip_unchanged: false,
+                })
+            }
+            Meta::List(list) if list.path.is_ident("track_dirty") => {
+                let mut track_dirty = TrackDirty {
+                    skip_unchanged: false,
+                };
+
+                for setting in
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                {
+                    match setting {
+                        Meta::Path(path) if path.is_ident("skip_unchanged") => {
+                            track_dirty.skip_unchanged = true
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                setting,
+                                "Valid setting is `track_dirty(skip_unchanged)`",
+                            ))
+                        }
+                    }
+                }
+
+                self.track_dirty = Some(track_dirty);
+            }
+            Meta::Path(path) if path.is_ident("observable") => {
+                self.observable = Some(Observable {
+                    values: false,
+                    name: None,
+                })
+            }
+            Meta::List(list) if list.path.is_ident("observable") => {
+                let mut observable = Observable {
+                    values: false,
+                    name: None,
+                };
+
+                for setting in
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                {
+                    match setting {
+                        Meta::Path(path) if path.is_ident("values") => observable.values = true,
+                        Meta::NameValue(mnv) if mnv.path.is_ident("name") => {
+                            let name = expect_lit_str(mnv.value, "name = \"ExampleEvent\"")?;
+                            observable.name = Some(name.parse()?);
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                setting,
+                                "Valid settings are `observable(values, name = \"ExampleEvent\")`",
+                            ))
+                        }
+                    }
+                }
+
+                self.observable = Some(observable);
+            }
+            Meta::Path(path) if path.is_ident("history") => {
+                self.history = Some(History { capacity: None })
+            }
+            Meta::List(list) if list.path.is_ident("history") => {
+                let mut history = History { capacity: None };
+
+                for setting in
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                {
+                    match setting {
+                        Meta::NameValue(MetaNameValue {
+                            path,
+                            value:
+                                Expr::Lit(ExprLit {
+                                    lit: Lit::Int(capacity),
+                                    ..
+                                }),
+                            ..
+                        }) if path.is_ident("capacity") => {
+                            history.capacity = Some(capacity.base10_parse()?)
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                setting,
+                                "Valid setting is `history(capacity = 100)`",
+                            ))
+                        }
+                    }
+                }
+
+                self.history = Some(history);
+            }
+            Meta::Path(path) if path.is_ident("versioned") => {
+                self.versioned = Some(Versioned::new())
+            }
+            Meta::List(list) if list.path.is_ident("versioned") => {
+                let mut versioned = Versioned::new();
+
+                for setting in
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                {
+                    match setting {
+                        Meta::Path(path) if path.is_ident("per_field") => {
+                            versioned.per_field = true
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                setting,
+                                "Valid setting is `versioned(per_field)`",
+                            ))
+                        }
+                    }
+                }
+
+                self.versioned = Some(versioned);
+            }
+            Meta::List(list) if list.path.is_ident("computed") => {
+                self.computed.push(list.try_into()?)
+            }
+            Meta::List(list) if list.path.is_ident("cached") => self.cached.push(list.try_into()?),
+            Meta::List(list) if list.path.is_ident("path") => self.paths.push(list.try_into()?),
+            Meta::Path(path) if path.is_ident("events") => {
+                self.events = Some(Events {
+                    sink: None,
+                    name: None,
+                })
+            }
+            Meta::List(list) if list.path.is_ident("events") => {
+                let mut events = Events {
+                    sink: None,
+                    name: None,
+                };
+
+                for setting in
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                {
+                    match setting {
+                        Meta::NameValue(mnv) if mnv.path.is_ident("sink") => {
+                            let sink = expect_lit_str(mnv.value, "sink = \"Self::record\"")?;
+                            events.sink = Some(sink.parse()?);
+                        }
+                        Meta::NameValue(mnv) if mnv.path.is_ident("name") => {
+                            let name = expect_lit_str(mnv.value, "name = \"ExampleEvent\"")?;
+                            events.name = Some(name.parse()?);
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                setting,
+                                "Valid settings are `events(sink = \"Self::record\", name = \"ExampleEvent\")`",
+                            ))
+                        }
+                    }
+                }
+
+                self.events = Some(events);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
 }
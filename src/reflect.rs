@@ -0,0 +1,154 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Ident, ItemStruct, Path};
+
+use crate::case::field_name;
+use crate::hooks::WriteHooks;
+use crate::props::FieldProps;
+
+/// String-keyed access through `dyn Any`, limited to what the generated accessors already expose:
+/// `get_field` needs a getter, `set_field` a setter and `get_field_mut` both, as visible as the struct.
+pub(crate) fn expand_reflect(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    impl_trait: Option<&Path>,
+    hooks: &WriteHooks,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let error_ident = format_ident!("{}ReflectError", struct_ident);
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { Self: 'static });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut get_arms = quote! {};
+    let mut get_mut_arms = quote! {};
+    let mut set_arms = quote! {};
+
+    for (index, (field_ident, field_props)) in field_map.iter().enumerate() {
+        let name = field_name(field_ident);
+        let ty = &field_props.ty;
+        // Never more than the accessors themselves expose.
+        let has_getter = field_props.has_getter_at(vis);
+
+        if has_getter {
+            get_arms.extend(quote! {
+                #name => ::core::option::Option::Some(&self.#field_ident),
+            });
+        }
+
+        match field_props.setter_at(vis) {
+            Some(setter) => {
+                let set = setter.call_path(&quote! { Self }, impl_trait);
+                let set = match (&setter.before, &setter.error) {
+                    (Some(_), Some(_)) => quote! {
+                        #set(self, *value).map_err(|_| #error_ident::Vetoed(#name))
+                    },
+                    (Some(_), None) => quote! {
+                        if #set(self, *value) {
+                            ::core::result::Result::Ok(())
+                        } else {
+                            ::core::result::Result::Err(#error_ident::Vetoed(#name))
+                        }
+                    },
+                    (None, _) => quote! {
+                        #set(self, *value);
+                        ::core::result::Result::Ok(())
+                    },
+                };
+
+                // Handing out `&mut` counts as a write.
+                if has_getter {
+                    let after_write = hooks.after_write(index, field_ident);
+
+                    get_mut_arms.extend(quote! {
+                        #name => {
+                            #after_write
+                            ::core::option::Option::Some(&mut self.#field_ident)
+                        }
+                    });
+                }
+
+                set_arms.extend(quote! {
+                    #name => match value.downcast::<#ty>() {
+                        ::core::result::Result::Ok(value) => {
+                            #set
+                        }
+                        ::core::result::Result::Err(value) => {
+                            ::core::result::Result::Err(#error_ident::TypeMismatch {
+                                field: #name,
+                                value,
+                            })
+                        }
+                    },
+                });
+            }
+            None if has_getter => set_arms.extend(quote! {
+                #name => ::core::result::Result::Err(#error_ident::ReadOnly(#name)),
+            }),
+            None => {}
+        }
+    }
+
+    let error_doc = format!("The error returned by [`{struct_ident}::set_field`].");
+
+    quote! {
+        #[doc = #error_doc]
+        #[derive(Debug)]
+        #vis enum #error_ident {
+            UnknownField(::std::string::String),
+            ReadOnly(&'static str),
+            /// The `before` hook of the setter refused the value.
+            Vetoed(&'static str),
+            /// Gives back the value that could not be set.
+            TypeMismatch {
+                field: &'static str,
+                value: ::std::boxed::Box<dyn ::core::any::Any>,
+            },
+        }
+
+        impl ::core::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::UnknownField(field) => write!(f, "unknown field `{}`", field),
+                    Self::ReadOnly(field) => write!(f, "field `{}` has no setter", field),
+                    Self::Vetoed(field) => write!(f, "the setter of field `{}` refused the value", field),
+                    Self::TypeMismatch { field, .. } => write!(f, "mismatched type for field `{}`", field),
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #vis fn get_field(&self, name: &str) -> ::core::option::Option<&dyn ::core::any::Any> {
+                match name {
+                    #get_arms
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #vis fn get_field_mut(&mut self, name: &str) -> ::core::option::Option<&mut dyn ::core::any::Any> {
+                match name {
+                    #get_mut_arms
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #vis fn set_field(
+                &mut self,
+                name: &str,
+                value: ::std::boxed::Box<dyn ::core::any::Any>,
+            ) -> ::core::result::Result<(), #error_ident> {
+                match name {
+                    #set_arms
+                    _ => ::core::result::Result::Err(#error_ident::UnknownField(name.into())),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, Result, Token, Type, Visibility,
+};
+
+use crate::enums::Kind;
+
+/// `pub name: String`
+pub(crate) struct SharedTraitDecl {
+    vis: Visibility,
+    field: Ident,
+    ty: Type,
+}
+
+impl Parse for SharedTraitDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis = input.parse()?;
+        let field = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+
+        Ok(Self { vis, field, ty })
+    }
+}
+
+pub(crate) fn expand_shared_traits(decls: Punctuated<SharedTraitDecl, Token![,]>) -> TokenStream {
+    decls
+        .into_iter()
+        .map(|SharedTraitDecl { vis, field, ty }| {
+            let get_trait = Kind::GetrRef.shared_trait_ident(&field);
+            let set_trait = Kind::Setr.shared_trait_ident(&field);
+            let get_name = Kind::GetrRef.into_ident(&field);
+            let set_name = Kind::Setr.into_ident(&field);
+            let new_val_name = format_ident!("new_{}", field);
+
+            // Mirrors the signatures generated by `get` and `set`.
+            let amp = if matches!(ty, Type::Reference(_)) {
+                quote! {}
+            } else {
+                quote! { & }
+            };
+
+            quote! {
+                #vis trait #get_trait {
+                    fn #get_name(&self) -> #amp #ty;
+                }
+
+                #vis trait #set_trait {
+                    fn #set_name(&mut self, #new_val_name: #ty);
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,28 @@
+use syn::{GenericArgument, PathArguments, Type};
+
+/// The type arguments of `ty` if it is one of the `names` types, e.g. `[T]` for `Option<T>`.
+/// Only the last path segment is compared, so `std::option::Option<T>` works but aliases don't.
+pub(crate) fn type_args<'a>(ty: &'a Type, names: &[&str]) -> Option<Vec<&'a Type>> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+
+    if !names.iter().any(|name| segment.ident == name) {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
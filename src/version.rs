@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ItemStruct};
+
+use crate::neutral::neutral_newtype;
+use crate::props::{FieldProps, Versioned};
+
+fn version_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}Version", struct_ident)
+}
+
+fn field_versions_ident(struct_ident: &Ident) -> Ident {
+    format_ident!("{}FieldVersions", struct_ident)
+}
+
+/// `__version: ExampleVersion`, unless the struct has its own `version` field.
+pub(crate) fn version_field(input: &ItemStruct, versioned: &Versioned) -> Option<TokenStream> {
+    let vis = &input.vis;
+    let field = &versioned.field;
+    let version_ident = version_ident(&input.ident);
+
+    (field != "version").then(|| {
+        quote! {
+            #[doc(hidden)]
+            #vis #field: #version_ident
+        }
+    })
+}
+
+/// `__field_versions: ExampleFieldVersions`, a version per field in declaration order.
+pub(crate) fn field_versions_field(input: &ItemStruct) -> TokenStream {
+    let vis = &input.vis;
+    let field_versions_ident = field_versions_ident(&input.ident);
+
+    quote! {
+        #[doc(hidden)]
+        #vis __field_versions: #field_versions_ident
+    }
+}
+
+/// The struct's counter, its own `version` field or the one in `__version`.
+fn counter(versioned: &Versioned) -> TokenStream {
+    let field = &versioned.field;
+
+    if field == "version" {
+        quote! { self.#field }
+    } else {
+        quote! { self.#field.0 }
+    }
+}
+
+/// Bumps the struct's version, and the version of the field at `index` with `per_field`.
+pub(crate) fn bump(versioned: &Versioned, index: usize, field_ident: &Ident) -> TokenStream {
+    let field = &versioned.field;
+
+    // Writing the counter itself is not a change to count.
+    if field_ident == field {
+        return quote! {};
+    }
+
+    let counter = counter(versioned);
+    let mut bump = quote! { #counter = #counter.wrapping_add(1); };
+
+    if versioned.per_field {
+        bump.extend(quote! {
+            self.__field_versions.0[#index] = self.__field_versions.0[#index].wrapping_add(1);
+        });
+    }
+
+    bump
+}
+
+/// The counter types, `version()`, and `x_version()` per field with `per_field`, as every field
+/// but the counter has a slot.
+pub(crate) fn expand_versioned(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    versioned: &Versioned,
+) -> TokenStream {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let field = &versioned.field;
+    let counter = counter(versioned);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let version_type = (field != "version").then(|| {
+        let doc = format!("The version of a [`{struct_ident}`], which never affects comparisons.");
+        neutral_newtype(
+            vis,
+            &version_ident(struct_ident),
+            quote! { u64 },
+            quote! { 0 },
+            &doc,
+        )
+    });
+
+    let field_versions_type = versioned.per_field.then(|| {
+        let field_count = field_map.len();
+        let doc = format!(
+            "The versions of the fields of a [`{struct_ident}`], which never affect comparisons."
+        );
+        neutral_newtype(
+            vis,
+            &field_versions_ident(struct_ident),
+            quote! { [u64; #field_count] },
+            quote! { [0; #field_count] },
+            &doc,
+        )
+    });
+
+    let field_version_fns = field_map
+        .iter()
+        .enumerate()
+        .filter(|(_, (field_ident, _))| versioned.per_field && field_ident != field)
+        .map(|(index, (field_ident, _))| {
+            let field_version = format_ident!("{}_version", field_ident);
+
+            quote! {
+                #vis fn #field_version(&self) -> u64 {
+                    self.__field_versions.0[#index]
+                }
+            }
+        });
+
+    quote! {
+        #version_type
+
+        #field_versions_type
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// The number of writes through the generated mutators so far.
+            #vis fn version(&self) -> u64 {
+                #counter
+            }
+
+            #(#field_version_fns)*
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Ident, ItemStruct, Result};
+
+use crate::case::field_name;
+use crate::hooks::WriteHooks;
+use crate::props::FieldProps;
+
+/// `ExampleVisitor`/`ExampleVisitorMut` and the `visit_fields(_mut)` methods walking them.
+/// Readable fields are the ones with a getter as visible as the struct, mutable ones need such a
+/// setter as well.
+pub(crate) fn expand_visit(
+    input: &ItemStruct,
+    field_map: &[(Ident, FieldProps)],
+    hooks: &WriteHooks,
+) -> Result<TokenStream> {
+    let vis = &input.vis;
+    let struct_ident = &input.ident;
+    let visitor_ident = format_ident!("{}Visitor", struct_ident);
+    let visitor_mut_ident = format_ident!("{}VisitorMut", struct_ident);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut visit_methods = quote! {};
+    let mut visit_mut_methods = quote! {};
+    let mut visit_calls = quote! {};
+    let mut visit_mut_calls = quote! {};
+
+    for (index, (field_ident, field_props)) in field_map.iter().enumerate() {
+        if !field_props.has_getter_at(vis) {
+            continue;
+        }
+
+        if field_ident == "field" {
+            return Err(Error::new_spanned(
+                field_ident,
+                "`visit` generates `visit_field` for every field, which collides with its generic `visit_field`",
+            ));
+        }
+
+        let name = field_name(field_ident);
+        let ty = &field_props.ty;
+        let visit = format_ident!("visit_{}", field_ident);
+
+        visit_methods.extend(quote! {
+            fn #visit(&mut self, value: &#ty) {
+                self.visit_field(#name, value)
+            }
+        });
+        visit_calls.extend(quote! {
+            visitor.#visit(&self.#field_ident);
+        });
+
+        if field_props.setter_at(vis).is_some() {
+            let visit_mut = format_ident!("visit_{}_mut", field_ident);
+
+            visit_mut_methods.extend(quote! {
+                fn #visit_mut(&mut self, value: &mut #ty) {
+                    self.visit_field_mut(#name, value)
+                }
+            });
+            // Every visited field counts as written.
+            let after_write = hooks.after_write(index, field_ident);
+
+            visit_mut_calls.extend(quote! {
+                visitor.#visit_mut(&mut self.#field_ident);
+                #after_write
+            });
+        }
+    }
+
+    let visitor_doc = format!(
+        "Visits the readable fields of [`{struct_ident}`], every method defaults to `visit_field`."
+    );
+    let visitor_mut_doc = format!(
+        "Visits the writable fields of [`{struct_ident}`], every method defaults to `visit_field_mut`."
+    );
+
+    Ok(quote! {
+        #[doc = #visitor_doc]
+        #vis trait #visitor_ident #generics #where_clause {
+            fn visit_field<__T: ?Sized + ::core::fmt::Debug>(&mut self, name: &'static str, value: &__T) {
+                let _ = (name, value);
+            }
+
+            #visit_methods
+        }
+
+        #[doc = #visitor_mut_doc]
+        #vis trait #visitor_mut_ident #generics #where_clause {
+            fn visit_field_mut<__T: ?Sized + ::core::fmt::Debug>(&mut self, name: &'static str, value: &mut __T) {
+                let _ = (name, value);
+            }
+
+            #visit_mut_methods
+        }
+
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            #vis fn visit_fields<__V: #visitor_ident #ty_generics>(&self, visitor: &mut __V) {
+                #visit_calls
+            }
+
+            #vis fn visit_fields_mut<__V: #visitor_mut_ident #ty_generics>(&mut self, visitor: &mut __V) {
+                #visit_mut_calls
+            }
+        }
+    })
+}
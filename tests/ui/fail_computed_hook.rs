@@ -0,0 +1,8 @@
+use get_set_macro::get_set;
+
+#[get_set(computed(name = "double", ty = "u32", expr = "self.value * 2", before = "Self::check"))]
+struct Example {
+    value: u32,
+}
+
+fn main() {}
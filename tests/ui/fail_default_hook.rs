@@ -0,0 +1,9 @@
+use get_set_macro::get_set;
+
+#[get_set(set)]
+struct Example {
+    #[gsflags(default(before = "Self::check"))]
+    age: u32,
+}
+
+fn main() {}
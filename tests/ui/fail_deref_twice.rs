@@ -0,0 +1,12 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    #[gsflags(deref)]
+    name: String,
+
+    #[gsflags(deref_mut)]
+    tags: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    #[gsflags(set(error = "String"))]
+    age: u32,
+}
+
+fn main() {}
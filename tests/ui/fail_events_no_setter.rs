@@ -0,0 +1,14 @@
+use get_set_macro::get_set;
+
+#[get_set(events, set)]
+struct Example {
+    name: String,
+
+    // Fields without a setter have no variant.
+    #[gsflags(skip, get)]
+    age: u32,
+}
+
+fn main() {
+    let _ = ExampleEvent::AgeSet(1);
+}
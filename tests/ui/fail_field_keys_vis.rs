@@ -0,0 +1,15 @@
+use get_set_macro::{field_traits, get_set};
+
+field_traits!();
+
+#[get_set(field_keys)]
+pub struct Example {
+    // The getter is private, so the public key of `pub struct Example` can't read it.
+    #[gsflags(get, set(vis = "pub"))]
+    secret: u32,
+}
+
+fn main() {
+    let example = Example { secret: 1 };
+    let _ = example.get::<example_fields::Secret>();
+}
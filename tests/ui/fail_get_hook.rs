@@ -0,0 +1,10 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    // Getters never write, so they have nothing to hook into.
+    #[gsflags(get(before = "Self::check"))]
+    name: String,
+}
+
+fn main() {}
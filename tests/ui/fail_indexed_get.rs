@@ -0,0 +1,12 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    #[gsflags(indexed)]
+    slots: [u8; 4],
+}
+
+fn main() {
+    let example = Example { slots: [0; 4] };
+    example.slots_get::<4>();
+}
@@ -0,0 +1,18 @@
+use get_set_macro::get_set;
+
+struct Limits {
+    max: u32,
+}
+
+#[get_set(path(name = "max", path = "limits.max", ty = "u32", get, set(before = "Self::check")))]
+struct Example {
+    limits: Limits,
+}
+
+impl Example {
+    fn check(&self, _old: &u32, _new: &u32) -> bool {
+        true
+    }
+}
+
+fn main() {}
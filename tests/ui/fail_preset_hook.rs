@@ -0,0 +1,9 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    #[gsflags(option(after = "Self::changed"))]
+    nickname: Option<String>,
+}
+
+fn main() {}
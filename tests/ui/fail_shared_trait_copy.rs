@@ -0,0 +1,10 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    // The traits declared by `shared_traits!` return references.
+    #[gsflags(get_copy(shared_trait))]
+    age: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use get_set_macro::get_set;
+
+#[get_set(set(before = "Self::check"))]
+struct Example {
+    age: u32,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use get_set_macro::get_set;
+
+#[get_set(visit)]
+pub struct Example {
+    // The getter is private, so the public visitor of `pub struct Example` doesn't visit it.
+    #[gsflags(get, set(vis = "pub"))]
+    secret: u32,
+}
+
+struct Peek;
+
+impl ExampleVisitor for Peek {
+    fn visit_secret(&mut self, value: &u32) {
+        let _ = value;
+    }
+}
+
+fn main() {
+    Example { secret: 1 }.visit_fields(&mut Peek);
+}
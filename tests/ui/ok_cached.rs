@@ -0,0 +1,107 @@
+use std::cell::Cell;
+
+use get_set_macro::get_set;
+
+#[get_set(
+    set,
+    history,
+    cached(
+        name = "area",
+        ty = "f64",
+        compute = "Self::compute_area",
+        depends_on(width, height),
+        doc = "The width times the height, computed once per change."
+    ),
+    cached(
+        name = "label",
+        ty = "String",
+        compute = "Self::compute_label",
+        vis = "pub(crate)"
+    )
+)]
+#[derive(Default, Clone, Debug, PartialEq)]
+struct Rect {
+    #[gsflags(history)]
+    width: f64,
+    height: f64,
+
+    #[gsflags(skip)]
+    computed: Cell<u32>,
+}
+
+impl Rect {
+    fn compute_area(&self) -> f64 {
+        self.computed.set(self.computed.get() + 1);
+        self.width * self.height
+    }
+
+    fn compute_label(&self) -> String {
+        format!("{}x{}", self.width, self.height)
+    }
+}
+
+// The cache is generic over the struct's generics, used by a cached type or not.
+#[get_set(
+    set,
+    cached(
+        name = "last",
+        ty = "Option<T>",
+        compute = "Self::compute_last",
+        depends_on(items)
+    ),
+    cached(
+        name = "count",
+        ty = "usize",
+        compute = "Self::compute_count",
+        depends_on(items)
+    )
+)]
+#[derive(Default)]
+struct Stack<T: Clone> {
+    items: Vec<T>,
+}
+
+impl<T: Clone> Stack<T> {
+    fn compute_last(&self) -> Option<T> {
+        self.items.last().cloned()
+    }
+
+    fn compute_count(&self) -> usize {
+        self.items.len()
+    }
+}
+
+fn main() {
+    let mut rect = Rect::default();
+    rect.set_width(2.0);
+    rect.set_height(3.0);
+
+    assert_eq!(6.0, *rect.get_area());
+    assert_eq!(6.0, *rect.get_area());
+    assert_eq!(1, rect.computed.get());
+    assert_eq!("2x3", rect.get_label());
+
+    rect.set_height(4.0);
+    assert_eq!(8.0, *rect.get_area());
+    assert_eq!(2, rect.computed.get());
+
+    // `label` does not depend on any field, it is only refreshed explicitly.
+    assert_eq!("2x3", rect.get_label());
+    rect.invalidate_caches();
+    assert_eq!("2x4", rect.get_label());
+
+    // Undo invalidates too.
+    rect.undo();
+    rect.undo();
+    assert_eq!(0.0, *rect.get_area());
+
+    // The cache does not affect comparisons.
+    let cloned = rect.clone();
+    assert_eq!(cloned, rect);
+
+    let mut stack = Stack::default();
+    assert_eq!(&None, stack.get_last());
+    stack.set_items(vec!['a', 'b']);
+    assert_eq!(&Some('b'), stack.get_last());
+    assert_eq!(&2, stack.get_count());
+}
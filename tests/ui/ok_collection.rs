@@ -0,0 +1,70 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use get_set_macro::get_set;
+
+type Tags = Vec<String>;
+
+#[get_set(track_dirty)]
+#[derive(Default)]
+struct Example {
+    #[gsflags(collection)]
+    items: Vec<u32>,
+
+    #[gsflags(collection(vis = "pub(crate)"))]
+    queue: VecDeque<u8>,
+
+    #[gsflags(collection)]
+    names: HashSet<String>,
+
+    #[gsflags(collection)]
+    ordered: BTreeSet<String>,
+
+    #[gsflags(collection(container = "Vec", item = "String"))]
+    tags: Tags,
+}
+
+#[get_set]
+#[derive(Default)]
+struct Generic<T> {
+    #[gsflags(collection)]
+    values: std::vec::Vec<T>,
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    example.push_items(1);
+    example.extend_items([2, 3]);
+    assert_eq!(3, example.items_len());
+    assert!(example.contains_items(&2));
+    assert_eq!(Some(1), example.remove_items(0));
+    assert_eq!(None, example.remove_items(5));
+    assert_eq!(vec![&2, &3], example.iter_items().collect::<Vec<_>>());
+    assert!(example.is_items_dirty());
+
+    example.push_queue(7);
+    assert_eq!(Some(7), example.remove_queue(0));
+    assert!(example.queue_is_empty());
+
+    // Sets are looked up by anything their items borrow as.
+    assert!(example.insert_names("Name".to_string()));
+    assert!(!example.insert_names("Name".to_string()));
+    assert!(example.contains_names("Name"));
+    example.clear_dirty();
+    assert!(!example.remove_names("Other"));
+    assert!(!example.is_names_dirty());
+    assert!(example.remove_names("Name"));
+
+    example.insert_ordered("b".to_string());
+    example.insert_ordered("a".to_string());
+    assert_eq!(vec!["a", "b"], example.iter_ordered().collect::<Vec<_>>());
+    example.clear_ordered();
+    assert!(example.ordered_is_empty());
+
+    example.push_tags("tag".to_string());
+    assert_eq!(1, example.tags_len());
+
+    let mut generic = Generic::default();
+    generic.push_values('a');
+    assert!(generic.contains_values(&'a'));
+}
@@ -0,0 +1,29 @@
+use get_set_macro::get_set;
+
+// Not `PartialEq`, so `contains_jobs` can't be called, but the rest is generated.
+struct Job {
+    id: u32,
+}
+
+#[get_set]
+#[derive(Default)]
+struct Queue {
+    #[gsflags(collection)]
+    jobs: Vec<Job>,
+
+    #[gsflags(collection)]
+    names: Vec<String>,
+}
+
+fn main() {
+    let mut queue = Queue::default();
+
+    queue.push_jobs(Job { id: 1 });
+    assert_eq!(1, queue.jobs_len());
+    assert_eq!(Some(1), queue.iter_jobs().next().map(|job| job.id));
+
+    // Items compare with anything they are `PartialEq` with.
+    queue.push_names("a".to_string());
+    assert!(queue.contains_names("a"));
+    assert!(queue.contains_names(&"a".to_string()));
+}
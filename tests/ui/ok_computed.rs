@@ -0,0 +1,66 @@
+use get_set_macro::get_set;
+
+trait Described {
+    fn description(&self) -> String;
+}
+
+#[get_set(
+    get,
+    default(vis = "pub", inline_always),
+    impl_trait = "Described",
+    computed(
+        name = "full_name",
+        ty = "String",
+        expr = "format!(\"{} {}\", self.first, self.last)",
+        doc = "The first and last name, separated by a space."
+    ),
+    computed(name = "initials", ty = "String", func = "Self::compute_initials", rename = "initials"),
+    computed(
+        name = "description",
+        ty = "String",
+        func = "Self::compute_initials",
+        trait_method = "description"
+    )
+)]
+#[derive(Default)]
+struct Example {
+    first: String,
+    last: String,
+}
+
+impl Example {
+    fn compute_initials(&self) -> String {
+        self.first.chars().take(1).chain(self.last.chars().take(1)).collect()
+    }
+}
+
+mod inner {
+    use get_set_macro::get_set;
+
+    #[get_set(
+        default(vis = "pub"),
+        computed(name = "area", ty = "f64", expr = "self.width * self.height")
+    )]
+    #[derive(Default)]
+    pub struct Rect {
+        pub width: f64,
+        pub height: f64,
+    }
+}
+
+fn main() {
+    let example = Example {
+        first: "Example".to_string(),
+        last: "Name".to_string(),
+    };
+
+    assert_eq!("Example Name", example.get_full_name());
+    assert_eq!("EN", example.initials());
+    assert_eq!("EN", example.description());
+
+    let rect = inner::Rect {
+        width: 2.0,
+        height: 3.0,
+    };
+    assert_eq!(6.0, rect.get_area());
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use get_set_macro::get_set;
+
+#[get_set(track_dirty, versioned)]
+#[derive(Default)]
+struct Stack {
+    #[gsflags(delegate(
+        pub fn len(&self) -> usize,
+        pub fn is_empty(&self) -> bool,
+        fn push(&mut self, value: u32),
+        fn pop(&mut self) -> Option<u32>,
+        fn iter(&self) -> std::slice::Iter<'_, u32>,
+    ))]
+    items: Vec<u32>,
+}
+
+#[get_set(default(vis = "pub(crate)", inline))]
+#[derive(Default)]
+struct Registry<K: std::hash::Hash + Eq, V> {
+    // A `where` clause would run into the next delegate, so it comes last.
+    #[gsflags(delegate(
+        fn insert(&mut self, key: K, value: V) -> Option<V>,
+        fn get<Q>(&self, key: &Q) -> Option<&V>
+        where
+            K: std::borrow::Borrow<Q>,
+            Q: std::hash::Hash + Eq + ?Sized,
+    ))]
+    entries: HashMap<K, V>,
+}
+
+fn main() {
+    let mut stack = Stack::default();
+    assert!(stack.is_empty());
+
+    stack.push(1);
+    stack.push(2);
+    assert_eq!(2, stack.len());
+    assert_eq!(vec![&1, &2], stack.iter().collect::<Vec<_>>());
+
+    // Mutating delegates count as writes.
+    assert!(stack.is_items_dirty());
+    assert_eq!(2, stack.version());
+
+    assert_eq!(Some(2), stack.pop());
+    assert_eq!(3, stack.version());
+
+    let mut registry = Registry::default();
+    registry.insert("key".to_string(), 1);
+    assert_eq!(Some(&1), registry.get("key"));
+}
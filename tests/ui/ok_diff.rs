@@ -0,0 +1,78 @@
+use get_set_macro::get_set;
+
+#[get_set(diff, field_enum, get)]
+#[derive(Clone)]
+struct Example {
+    name: String,
+
+    age: u32,
+
+    #[gsflags(skip)]
+    skipped: f32,
+
+    // Skipped, but listed in `ExampleField` for its own getter.
+    #[gsflags(skip, get)]
+    id: u8,
+}
+
+// Compared, but not `Debug`.
+#[derive(PartialEq)]
+struct Opaque(u8);
+
+#[get_set(diff, get(vis = "pub"))]
+pub struct Public {
+    shown: Opaque,
+
+    // The getter is private, so the public `PublicChange` doesn't list it.
+    #[gsflags(get)]
+    secret: u8,
+}
+
+#[get_set(diff)]
+struct Generic<T> {
+    #[gsflags(get)]
+    value: T,
+}
+
+fn main() {
+    let before = Example {
+        name: "ExampleName".to_string(),
+        age: 55,
+        skipped: 1.0,
+        id: 1,
+    };
+    let mut after = before.clone();
+    after.age = 56;
+    after.skipped = 2.0;
+
+    // Skipped fields have no getter and are not compared.
+    let changes = before.diff(&after);
+
+    assert_eq!(vec![ExampleChange::Age { old: &55, new: &56 }], changes);
+    assert_eq!("age", changes[0].field_name());
+    assert_eq!(ExampleField::Age, changes[0].field());
+    assert!(before.diff(&before).is_empty());
+
+    after.id = 2;
+    assert_eq!(ExampleField::Id, before.diff(&after)[1].field());
+
+    let changes = Public {
+        shown: Opaque(1),
+        secret: 1,
+    }
+    .diff(&Public {
+        shown: Opaque(2),
+        secret: 2,
+    })
+    .len();
+    assert_eq!(1, changes);
+    let (old, new) = (Opaque(1), Opaque(2));
+    let change = PublicChange::Shown {
+        old: &old,
+        new: &new,
+    };
+    assert_eq!("Shown { .. }", format!("{change:?}"));
+
+    let changes = Generic { value: 'a' }.diff(&Generic { value: 'b' }).len();
+    assert_eq!(1, changes);
+}
@@ -0,0 +1,85 @@
+use get_set_macro::get_set;
+
+// Only `Clone`, which the sink needs, is required of the field types.
+#[derive(Clone, Default)]
+struct Opaque;
+
+#[get_set(events(sink = "Self::record"), get, set)]
+#[derive(Default)]
+struct Example {
+    name: String,
+    age: u32,
+    opaque: Opaque,
+
+    // The only setter converts its argument, so there is no variant and nothing is emitted.
+    #[gsflags(get, set(ty = "u8"))]
+    small: u16,
+
+    #[gsflags(skip)]
+    log: Vec<ExampleEvent>,
+}
+
+impl Example {
+    fn record(&mut self, event: ExampleEvent) {
+        self.log.push(event);
+    }
+}
+
+#[get_set(events, set)]
+#[derive(Default)]
+struct Replayed {
+    name: String,
+
+    // No setter, so no `ReplayedEvent::AgeSet`, see `fail_events_no_setter.rs`.
+    #[gsflags(skip, get)]
+    age: u32,
+}
+
+// Both features default to `{Struct}Event`, so one of them is renamed.
+#[get_set(events(name = "ObservedChange"), observable, set)]
+#[derive(Default)]
+struct Observed {
+    value: u8,
+}
+
+#[get_set(events, set)]
+#[derive(Default)]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {
+    let mut example = Example::default();
+    example.set_name("ExampleName".to_string());
+    example.set_age(55);
+    example.set_opaque(Opaque);
+    example.set_small(7);
+
+    assert!(matches!(
+        example.log.as_slice(),
+        [
+            ExampleEvent::NameSet(name),
+            ExampleEvent::AgeSet(55),
+            ExampleEvent::OpaqueSet(Opaque),
+        ] if name == "ExampleName"
+    ));
+
+    // Replaying the log rebuilds the same state.
+    let mut replayed = Replayed::default();
+    for event in example.log.clone() {
+        match event {
+            ExampleEvent::NameSet(name) => replayed.apply_event(ReplayedEvent::NameSet(name)),
+            ExampleEvent::AgeSet(_) | ExampleEvent::OpaqueSet(_) => {}
+        }
+    }
+    assert_eq!("ExampleName", replayed.name);
+    assert_eq!(&0, replayed.get_age());
+
+    let mut observed = Observed::default();
+    observed.apply_event(ObservedChange::ValueSet(3));
+    assert_eq!(3, observed.value);
+
+    let mut generic = Generic::default();
+    generic.apply_event(GenericEvent::ValueSet('a'));
+    assert_eq!('a', generic.value);
+}
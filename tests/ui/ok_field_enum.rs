@@ -0,0 +1,35 @@
+use get_set_macro::get_set;
+
+#[get_set(field_enum, get)]
+struct Example {
+    name: String,
+
+    #[gsflags(set)]
+    user_age: u32,
+
+    // Not part of `ExampleField`
+    #[gsflags(skip)]
+    skipped: f32,
+}
+
+fn main() {
+    let example = Example {
+        name: "ExampleName".to_string(),
+        user_age: 55,
+        skipped: 1.0,
+    };
+
+    assert_eq!(&["name", "user_age"], Example::FIELD_NAMES);
+    assert_eq!("name", Example::NAME_FIELD);
+    assert_eq!("user_age", Example::USER_AGE_FIELD);
+
+    assert_eq!(&[ExampleField::Name, ExampleField::UserAge], ExampleField::ALL);
+    assert_eq!("user_age", ExampleField::UserAge.field_name());
+    assert_eq!("name", ExampleField::Name.to_string());
+
+    assert_eq!(Ok(ExampleField::UserAge), "user_age".parse());
+    let err = "skipped".parse::<ExampleField>().unwrap_err();
+    assert_eq!("unknown field `skipped`", err.to_string());
+
+    let _ = (example.get_name(), example.skipped);
+}
@@ -0,0 +1,48 @@
+use get_set_macro::{field_traits, get_set};
+
+field_traits!();
+
+#[get_set(field_keys, get)]
+struct Example<T> {
+    #[gsflags(set)]
+    name: String,
+
+    // Only readable, so `example_fields::Value` implements `GetField` but not `SetField`.
+    value: T,
+
+    #[gsflags(skip)]
+    skipped: f32,
+}
+
+// Written once, works for any field of any struct.
+fn describe<S, F: GetField<S>>(s: &S) -> String
+where
+    F::Type: std::fmt::Display,
+{
+    format!("{} = {}", F::NAME, F::get(s))
+}
+
+fn overwrite<S, F: SetField<S>>(s: &mut S, value: F::Type) {
+    F::set(s, value)
+}
+
+fn main() {
+    let mut example = Example {
+        name: "ExampleName".to_string(),
+        value: 5u8,
+        skipped: 1.0,
+    };
+
+    assert_eq!("ExampleName", example.get::<example_fields::Name>());
+    assert_eq!(5, *example.get::<example_fields::Value>());
+    assert_eq!("value = 5", describe::<_, example_fields::Value>(&example));
+
+    example.set::<example_fields::Name>("NewName".to_string());
+    assert_eq!("NewName", example.get_name().as_str());
+
+    overwrite::<_, example_fields::Name>(&mut example, "OtherName".to_string());
+    assert_eq!("name = OtherName", describe::<_, example_fields::Name>(&example));
+
+    // There is no key for skipped fields.
+    let _ = example.skipped;
+}
@@ -0,0 +1,49 @@
+mod config {
+    use get_set_macro::get_set;
+
+    #[get_set(flattenable, get, set, default(vis = "pub"))]
+    #[derive(Default)]
+    pub struct InnerConfig {
+        pub timeout: u64,
+
+        #[gsflags(get(rename = "host_name"))]
+        pub host: String,
+
+        #[gsflags(skip)]
+        pub internal: u8,
+    }
+}
+
+use config::InnerConfig;
+use get_set_macro::get_set;
+
+#[get_set(track_dirty, versioned)]
+#[derive(Default)]
+struct OuterConfig {
+    #[gsflags(flatten(vis = "pub"))]
+    inner: config::InnerConfig,
+
+    #[gsflags(flatten(prefix = "backup"))]
+    other: InnerConfig,
+
+    #[gsflags(flatten(prefix = ""))]
+    plain: InnerConfig,
+}
+
+fn main() {
+    let mut outer = OuterConfig::default();
+
+    outer.set_inner_timeout(30);
+    assert_eq!(30, *outer.get_inner_timeout());
+    assert_eq!(30, *outer.inner.get_timeout());
+
+    // Writes through the forwarded setters are writes of the outer field.
+    assert!(outer.is_inner_dirty());
+    assert_eq!(1, outer.version());
+
+    outer.set_backup_host("backup.example".to_string());
+    assert_eq!("backup.example", outer.backup_host_name());
+
+    outer.set_timeout(5);
+    assert_eq!(5, *outer.get_timeout());
+}
@@ -0,0 +1,65 @@
+use get_set_macro::get_set;
+
+#[get_set(history(capacity = 2), track_dirty, get, set)]
+#[derive(Default, Clone, Debug, PartialEq)]
+struct Example {
+    #[gsflags(history)]
+    name: String,
+
+    #[gsflags(history)]
+    age: u32,
+
+    // Not recorded, undo skips over it.
+    note: String,
+}
+
+#[get_set(history, set)]
+#[derive(Default)]
+struct Generic<T: Clone> {
+    #[gsflags(history)]
+    value: T,
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    assert!(!example.can_undo());
+
+    example.set_name("ExampleName".to_string());
+    example.set_note("Note".to_string());
+    example.set_age(55);
+    example.set_age(56);
+    example.clear_dirty();
+
+    // Only two writes are kept.
+    assert!(example.undo());
+    assert_eq!(55, *example.get_age());
+    assert!(example.is_age_dirty());
+    assert!(example.undo());
+    assert_eq!(0, *example.get_age());
+    assert!(!example.undo());
+    assert_eq!("ExampleName", example.get_name().as_str());
+    assert_eq!("Note", example.get_note().as_str());
+
+    assert!(example.redo());
+    assert!(example.redo());
+    assert!(!example.can_redo());
+    assert_eq!(56, *example.get_age());
+
+    // A new write drops whatever could be redone.
+    example.undo();
+    example.set_name("NewName".to_string());
+    assert!(!example.redo());
+
+    // The history is cloned along with the struct, and does not affect comparisons.
+    let mut cloned = example.clone();
+    assert!(cloned.undo());
+    assert_eq!("ExampleName", cloned.get_name().as_str());
+    example.clear_history();
+    assert!(!example.can_undo());
+
+    let mut generic = Generic::default();
+    generic.set_value('a');
+    assert!(generic.undo());
+    assert_eq!('\0', generic.value);
+}
@@ -0,0 +1,35 @@
+use get_set_macro::get_set;
+
+trait Named {
+    fn name(&self) -> &str;
+    fn set_name(&mut self, n: String);
+}
+
+#[get_set(impl_trait = "Named", get)]
+struct Example<T> {
+    // `name` and `set_name` are placed in `impl Named for Example`, `get_name` is still inherent.
+    // The getter's return type is overridden to match the trait's signature.
+    #[gsflags(get(trait_method = "name", ty = "&str"), set(trait_method = "set_name"))]
+    name: String,
+
+    value: T,
+}
+
+fn rename<N: Named>(named: &mut N, name: &str) {
+    named.set_name(name.to_string());
+}
+
+fn main() {
+    let mut example = Example {
+        name: "ExampleName".to_string(),
+        value: 5u8,
+    };
+
+    assert_eq!("ExampleName", example.name());
+    assert_eq!("ExampleName", example.get_name().as_str());
+    assert_eq!(5, *example.get_value());
+
+    rename(&mut example, "NewName");
+
+    assert_eq!("NewName", example.name());
+}
@@ -0,0 +1,52 @@
+use get_set_macro::get_set;
+
+#[get_set(versioned)]
+#[derive(Default)]
+struct Example {
+    #[gsflags(indexed)]
+    slots: [u8; 4],
+
+    #[gsflags(indexed(vis = "pub(crate)"))]
+    items: Vec<String>,
+
+    #[gsflags(indexed)]
+    boxed: Box<[u32]>,
+}
+
+#[get_set]
+struct Generic<T, const N: usize> {
+    #[gsflags(indexed)]
+    values: [T; N],
+}
+
+fn main() {
+    let mut example = Example {
+        items: vec!["a".to_string()],
+        boxed: vec![1, 2].into_boxed_slice(),
+        ..Default::default()
+    };
+
+    assert_eq!(Some(&0), example.slots_at(3));
+    assert_eq!(None, example.slots_at(4));
+    assert_eq!(Ok(0), example.set_slots_at(1, 7));
+    assert_eq!(
+        Err(ExampleIndexError { index: 4, len: 4 }),
+        example.set_slots_at(4, 7)
+    );
+    assert_eq!(1, example.version());
+    assert_eq!(7, *example.slots_get::<1>());
+    assert_eq!(&[0, 7, 0, 0], example.slots_slice());
+
+    example.items_at_mut(0).unwrap().push('b');
+    assert_eq!(Some(&"ab".to_string()), example.items_at(0));
+    assert_eq!(
+        "index 1 out of bounds for length 1",
+        example.set_items_at(1, String::new()).unwrap_err().to_string()
+    );
+
+    assert_eq!(Ok(2), example.set_boxed_at(1, 3));
+    assert_eq!(&[1, 3], example.boxed_slice());
+
+    let generic = Generic { values: ['a', 'b'] };
+    assert_eq!('b', *generic.values_get::<1>());
+}
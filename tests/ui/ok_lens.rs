@@ -0,0 +1,40 @@
+use get_set_macro::{get_set, lens_traits};
+
+lens_traits!();
+
+#[get_set(lens)]
+struct Inner<T> {
+    x: i32,
+
+    value: T,
+}
+
+#[get_set]
+struct Outer {
+    #[gsflags(lens, get(rename = "inner"))]
+    inner: Inner<char>,
+
+    #[gsflags(lens(rename = "label"))]
+    name: String,
+}
+
+// Generic binding code only knows about the lens.
+fn increment<S, L: Lens<S, Target = i32>>(source: &mut S, lens: &L) {
+    *lens.get_mut(source) += 1;
+}
+
+fn main() {
+    let mut outer = Outer {
+        inner: Inner { x: 1, value: 'a' },
+        name: "ExampleName".to_string(),
+    };
+
+    let x = Outer::inner_lens().then(Inner::x_lens());
+
+    increment(&mut outer, &x);
+
+    assert_eq!(2, *x.get(&outer));
+    assert_eq!(2, outer.inner().x);
+    assert_eq!('a', *Outer::inner_lens().then(Inner::value_lens()).get(&outer));
+    assert_eq!("ExampleName", Outer::label().get(&outer));
+}
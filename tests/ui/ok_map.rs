@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, HashMap};
+
+use get_set_macro::get_set;
+
+type Registry = HashMap<String, u32>;
+
+// The preset's `get_x` wins over the struct-wide getter.
+#[get_set(get, track_dirty)]
+#[derive(Default)]
+struct Example {
+    #[gsflags(map)]
+    counts: HashMap<String, u32>,
+
+    #[gsflags(map(vis = "pub(crate)"))]
+    ordered: BTreeMap<u8, String>,
+
+    #[gsflags(map(map = "HashMap", key = "String", value = "u32"))]
+    registry: Registry,
+
+    note: String,
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    assert_eq!(None, example.insert_counts("a".to_string(), 1));
+    assert_eq!(Some(1), example.insert_counts("a".to_string(), 2));
+    assert!(example.is_counts_dirty());
+
+    // Looked up by anything the keys borrow as.
+    assert_eq!(Some(&2), example.get_counts("a"));
+    *example.get_counts_mut("a").unwrap() += 1;
+    *example.counts_entry("b".to_string()).or_insert(0) += 5;
+    assert_eq!(2, example.counts_len());
+
+    let mut keys = example.counts_keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(vec!["a", "b"], keys);
+    assert_eq!(8, example.counts_values().sum::<u32>());
+
+    example.clear_dirty();
+    assert_eq!(None, example.remove_counts("c"));
+    assert!(!example.is_dirty());
+    assert_eq!(Some(3), example.remove_counts("a"));
+
+    example.insert_ordered(2, "two".to_string());
+    example.insert_ordered(1, "one".to_string());
+    assert_eq!(vec!["one", "two"], example.ordered_values().collect::<Vec<_>>());
+
+    example.insert_registry("key".to_string(), 1);
+    assert_eq!(Some(&1), example.get_registry("key"));
+
+    assert_eq!("", example.get_note());
+}
@@ -0,0 +1,38 @@
+use get_set_macro::get_set;
+
+// Writes through `get_field_mut` and `visit_fields_mut` are tracked like setters.
+#[get_set(reflect, visit, track_dirty, versioned(per_field), get, set)]
+#[derive(Default)]
+struct Example {
+    name: String,
+    age: u32,
+}
+
+struct Birthday;
+
+impl ExampleVisitorMut for Birthday {
+    fn visit_age_mut(&mut self, value: &mut u32) {
+        *value += 1;
+    }
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    example
+        .get_field_mut("name")
+        .and_then(|v| v.downcast_mut::<String>())
+        .unwrap()
+        .push('!');
+    assert_eq!("!", example.get_name().as_str());
+    assert!(example.is_name_dirty());
+    assert!(!example.is_age_dirty());
+    assert_eq!(1, example.version());
+    assert_eq!(1, example.name_version());
+
+    example.clear_dirty();
+    example.visit_fields_mut(&mut Birthday);
+    assert_eq!(&1, example.get_age());
+    assert!(example.is_age_dirty());
+    assert_eq!(1, example.age_version());
+}
@@ -0,0 +1,65 @@
+use get_set_macro::get_set;
+
+#[get_set(track_dirty, versioned)]
+#[derive(Default)]
+struct Counter {
+    #[gsflags(numeric)]
+    hits: u32,
+
+    #[gsflags(numeric(mode = "wrapping"))]
+    tick: u8,
+
+    #[gsflags(numeric(mode = "saturating", vis = "pub(crate)"))]
+    level: i8,
+
+    #[gsflags(numeric(mode = "checked"))]
+    budget: usize,
+
+    #[gsflags(numeric)]
+    ratio: f64,
+
+    #[gsflags(bool)]
+    active: bool,
+}
+
+fn main() {
+    let mut counter = Counter {
+        tick: u8::MAX,
+        level: i8::MIN + 1,
+        budget: 1,
+        ..Default::default()
+    };
+
+    counter.incr_hits();
+    counter.add_hits(4);
+    counter.decr_hits();
+    assert_eq!(4, counter.hits);
+    assert!(counter.is_hits_dirty());
+
+    counter.incr_tick();
+    assert_eq!(0, counter.tick);
+
+    counter.sub_level(10);
+    counter.decr_level();
+    assert_eq!(i8::MIN, counter.level);
+
+    assert!(counter.decr_budget());
+    assert!(!counter.sub_budget(1));
+    assert_eq!(0, counter.budget);
+    assert!(counter.add_budget(3));
+    assert_eq!(3, counter.budget);
+
+    counter.add_ratio(0.5);
+    counter.incr_ratio();
+    assert_eq!(1.5, counter.ratio);
+
+    assert!(!counter.is_active());
+    let version = counter.version();
+    counter.disable_active();
+    assert_eq!(version, counter.version());
+    counter.enable_active();
+    assert!(counter.is_active());
+    counter.toggle_active();
+    assert!(!counter.is_active());
+    assert_eq!(version + 2, counter.version());
+}
@@ -0,0 +1,72 @@
+use std::{cell::RefCell, rc::Rc};
+
+use get_set_macro::get_set;
+
+#[get_set(observable, field_enum, set)]
+#[derive(Default, Clone, Debug, PartialEq)]
+struct Example {
+    name: String,
+
+    #[gsflags(get)]
+    age: u32,
+}
+
+#[get_set(observable(values), set)]
+#[derive(Default)]
+struct Values<T: Clone + 'static> {
+    value: T,
+}
+
+// Without setters the event enum has no variants.
+#[get_set(observable, field_enum, get)]
+#[derive(Default)]
+struct ReadOnly {
+    id: u32,
+}
+
+fn main() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+
+    let mut example = Example::default();
+    let sink = events.clone();
+    example.subscribe(move |event| sink.borrow_mut().push(*event));
+
+    example.set_name("ExampleName".to_string());
+    example.set_age(55);
+
+    assert_eq!(
+        vec![ExampleEvent::Name, ExampleEvent::Age],
+        *events.borrow()
+    );
+    assert_eq!("age", ExampleEvent::Age.field_name());
+    assert_eq!(ExampleField::Age, ExampleEvent::Age.field());
+
+    // Observers are not cloned and do not affect comparisons.
+    let mut cloned = example.clone();
+    assert_eq!(example, cloned);
+    cloned.set_age(56);
+    assert_eq!(2, events.borrow().len());
+
+    let changes = Rc::new(RefCell::new(Vec::new()));
+
+    let mut values = Values::default();
+    let sink = changes.clone();
+    values.subscribe(move |event| {
+        // Debug doesn't need `T: Debug`, it only names the variant.
+        assert_eq!("Value { .. }", format!("{event:?}"));
+
+        match event {
+            ValuesEvent::Value { old, new } => sink.borrow_mut().push((**old, **new)),
+            _ => unreachable!(),
+        }
+    });
+
+    values.set_value('a');
+    values.set_value('b');
+
+    assert_eq!(vec![('\0', 'a'), ('a', 'b')], *changes.borrow());
+
+    let mut read_only = ReadOnly::default();
+    read_only.subscribe(|event| match *event {});
+    assert_eq!(&0, read_only.get_id());
+}
@@ -0,0 +1,41 @@
+use get_set_macro::get_set;
+
+#[get_set(track_dirty, history)]
+#[derive(Default)]
+struct Example {
+    #[gsflags(option, history)]
+    nickname: Option<String>,
+
+    // An explicit setter wins over the preset's, whatever the order.
+    #[gsflags(option(vis = "pub(crate)"), get, set)]
+    limit: std::option::Option<u32>,
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    assert!(!example.has_nickname());
+    assert_eq!(None, example.nickname());
+
+    example.set_nickname("Nick".to_string());
+    assert!(example.has_nickname());
+    assert_eq!(Some(&"Nick".to_string()), example.nickname());
+    assert!(example.is_nickname_dirty());
+
+    // The preset's setter records history like any setter.
+    assert!(example.undo());
+    assert_eq!(None, example.nickname());
+    assert!(example.redo());
+
+    example.nickname_mut().unwrap().push('!');
+    assert_eq!(Some("Nick!".to_string()), example.clear_nickname());
+    assert_eq!(None, example.clear_nickname());
+
+    assert_eq!(&None, example.get_limit());
+    *example.limit_or_insert_with(|| 1) += 1;
+    assert_eq!(Some(&2), example.limit());
+    assert!(example.is_limit_dirty());
+
+    example.set_limit(None);
+    assert!(!example.has_limit());
+}
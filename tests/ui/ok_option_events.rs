@@ -0,0 +1,66 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use get_set_macro::get_set;
+
+#[get_set(
+    observable,
+    history,
+    events(sink = "Self::record", name = "ProfileChange")
+)]
+#[derive(Default)]
+struct Profile {
+    #[gsflags(option, history)]
+    nickname: Option<String>,
+
+    #[gsflags(skip)]
+    log: Vec<ProfileChange>,
+}
+
+impl Profile {
+    fn record(&mut self, event: ProfileChange) {
+        self.log.push(event);
+    }
+}
+
+fn main() {
+    let mut profile = Profile::default();
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let observer_seen = Rc::clone(&seen);
+    profile.subscribe(move |event| observer_seen.borrow_mut().push(event.field_name()));
+
+    // The preset's setter notifies and emits like any setter.
+    profile.set_nickname("Nick".to_string());
+    assert_eq!(vec!["nickname"], *seen.borrow());
+    assert!(matches!(
+        profile.log.as_slice(),
+        [ProfileChange::NicknameSet(Some(nickname))] if nickname == "Nick"
+    ));
+
+    let mut replica = Profile::default();
+    for event in profile.log.clone() {
+        replica.apply_event(event);
+    }
+    assert_eq!(Some(&"Nick".to_string()), replica.nickname());
+
+    replica.apply_event(ProfileChange::NicknameSet(None));
+    assert!(!replica.has_nickname());
+
+    // Clearing is a setter of `None`, down to the bookkeeping.
+    assert_eq!(Some("Nick".to_string()), profile.clear_nickname());
+    assert_eq!(vec!["nickname", "nickname"], *seen.borrow());
+    assert!(matches!(
+        profile.log.last(),
+        Some(ProfileChange::NicknameSet(None))
+    ));
+
+    let mut replica = Profile::default();
+    for event in profile.log.clone() {
+        replica.apply_event(event);
+    }
+    assert!(!replica.has_nickname());
+
+    assert!(profile.undo());
+    assert_eq!(Some(&"Nick".to_string()), profile.nickname());
+}
@@ -0,0 +1,56 @@
+use get_set_macro::get_set;
+
+#[derive(Default)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Default)]
+struct Inner {
+    limits: Limits,
+    backup: Option<Limits>,
+}
+
+#[get_set(
+    track_dirty,
+    default(vis = "pub(crate)"),
+    path(name = "max_limit", path = "inner.limits.max", ty = "u32", get, set),
+    path(name = "backup_max", path = "inner.backup?.max", ty = "u32", get, set(rename = "update_backup_max")),
+    path(name = "extra_max", path = "extra?.backup?.max", ty = "u32", get, set),
+    path(name = "first", path = "pair.0", ty = "u8", get)
+)]
+#[derive(Default)]
+struct Example {
+    inner: Inner,
+    extra: Option<Inner>,
+    pair: (u8, u8),
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    example.set_max_limit(10);
+    assert_eq!(10, *example.get_max_limit());
+    assert!(example.is_inner_dirty());
+    example.clear_dirty();
+
+    // `Option` hops make the accessors fallible.
+    assert_eq!(None, example.get_backup_max());
+    assert!(!example.update_backup_max(5));
+    assert!(!example.is_dirty());
+
+    example.inner.backup = Some(Limits::default());
+    assert!(example.update_backup_max(5));
+    assert_eq!(Some(&5), example.get_backup_max());
+
+    assert!(!example.set_extra_max(1));
+    example.extra = Some(Inner {
+        backup: Some(Limits { max: 2 }),
+        ..Default::default()
+    });
+    assert_eq!(Some(&2), example.get_extra_max());
+    assert!(example.set_extra_max(3));
+    assert_eq!(Some(&3), example.get_extra_max());
+
+    assert_eq!(0, *example.get_first());
+}
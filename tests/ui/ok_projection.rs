@@ -0,0 +1,48 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use get_set_macro::get_set;
+
+#[get_set]
+#[derive(Default)]
+struct Names {
+    #[gsflags(as_ref, as_mut, deref, deref_mut, borrow, index)]
+    names: Vec<String>,
+
+    #[gsflags(as_ref)]
+    label: String,
+}
+
+#[get_set]
+struct Wrapper<'a, K, V> {
+    #[gsflags(deref, index)]
+    map: HashMap<K, V>,
+
+    #[gsflags(as_ref)]
+    note: &'a str,
+}
+
+fn takes_str<S: AsRef<String>>(s: S) -> usize {
+    s.as_ref().len()
+}
+
+fn main() {
+    let mut names = Names::default();
+    names.push("ExampleName".to_string());
+    AsMut::<Vec<String>>::as_mut(&mut names).push("OtherName".to_string());
+
+    assert_eq!(2, names.len());
+    assert_eq!("ExampleName", names[0]);
+    assert_eq!(&names[..1], &["ExampleName".to_string()]);
+    assert_eq!(2, AsRef::<Vec<String>>::as_ref(&names).len());
+    assert_eq!(2, Borrow::<Vec<String>>::borrow(&names).len());
+    assert_eq!(0, takes_str(&names));
+
+    let wrapper = Wrapper {
+        map: HashMap::from([("key", 1)]),
+        note: "note",
+    };
+    assert_eq!(1, wrapper["key"]);
+    assert!(wrapper.contains_key("key"));
+    assert_eq!("note", *AsRef::<&str>::as_ref(&wrapper));
+}
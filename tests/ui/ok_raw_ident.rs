@@ -0,0 +1,40 @@
+use std::fmt::Debug;
+
+use get_set_macro::get_set;
+
+// Raw identifiers are named without their `r#` everywhere.
+#[get_set(field_enum, reflect, visit, diff, track_dirty, observable, get, set)]
+#[derive(Clone, Default)]
+struct Example {
+    r#type: String,
+}
+
+#[derive(Default)]
+struct Names(Vec<&'static str>);
+
+impl ExampleVisitor for Names {
+    fn visit_field<V: ?Sized + Debug>(&mut self, name: &'static str, _value: &V) {
+        self.0.push(name);
+    }
+}
+
+fn main() {
+    let mut example = Example::default();
+    let before = example.clone();
+
+    assert_eq!(&["type"], Example::FIELD_NAMES);
+    assert_eq!("type", Example::TYPE_FIELD);
+    assert_eq!("type", ExampleField::Type.field_name());
+
+    example.set_type("a".to_string());
+    assert!(example.get_field("type").is_some());
+    assert_eq!(vec!["type"], example.dirty_fields().collect::<Vec<_>>());
+    assert_eq!("type", before.diff(&example)[0].field_name());
+
+    let mut names = Names::default();
+    example.visit_fields(&mut names);
+    assert_eq!(vec!["type"], names.0);
+
+    example.subscribe(|event| assert_eq!("type", event.field_name()));
+    example.set_type("b".to_string());
+}
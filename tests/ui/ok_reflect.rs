@@ -0,0 +1,60 @@
+use std::any::Any;
+
+use get_set_macro::get_set;
+
+#[get_set(reflect)]
+struct Example<T> {
+    #[gsflags(get, set)]
+    name: String,
+
+    // Readable only, `set_field` and `get_field_mut` refuse it.
+    #[gsflags(get)]
+    value: T,
+
+    // Never visible through reflection.
+    hidden: u32,
+}
+
+fn main() {
+    let mut example = Example {
+        name: "ExampleName".to_string(),
+        value: 5u8,
+        hidden: 1,
+    };
+
+    assert_eq!(
+        Some("ExampleName"),
+        example.get_field("name").and_then(|v| v.downcast_ref::<String>()).map(String::as_str)
+    );
+    assert_eq!(Some(&5u8), example.get_field("value").and_then(|v| v.downcast_ref()));
+    assert!(example.get_field("hidden").is_none());
+
+    example.set_field("name", Box::new("NewName".to_string())).unwrap();
+    assert_eq!("NewName", example.get_name().as_str());
+
+    example
+        .get_field_mut("name")
+        .and_then(|v| v.downcast_mut::<String>())
+        .unwrap()
+        .push('!');
+    assert_eq!("NewName!", example.get_name().as_str());
+    assert!(example.get_field_mut("value").is_none());
+
+    match example.set_field("name", Box::new(5u32)) {
+        Err(ExampleReflectError::TypeMismatch { field, value }) => {
+            assert_eq!("name", field);
+            assert_eq!(Some(&5u32), (value as Box<dyn Any>).downcast_ref());
+        }
+        _ => panic!("expected a type mismatch"),
+    }
+    assert!(matches!(
+        example.set_field("value", Box::new(6u8)),
+        Err(ExampleReflectError::ReadOnly("value"))
+    ));
+    assert_eq!(
+        "unknown field `hidden`",
+        example.set_field("hidden", Box::new(2u32)).unwrap_err().to_string()
+    );
+
+    assert_eq!(1, example.hidden);
+}
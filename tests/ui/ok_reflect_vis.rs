@@ -0,0 +1,49 @@
+use get_set_macro::{field_traits, get_set};
+
+field_traits!();
+
+// The string and key based access is as visible as the struct, so it only covers
+// the accessors that are at least as visible.
+#[get_set(reflect, field_keys)]
+pub struct Example {
+    #[gsflags(get(vis = "pub"), set(vis = "pub"))]
+    name: String,
+
+    #[gsflags(get(vis = "pub"), set)]
+    id: u32,
+
+    #[gsflags(get, set(vis = "pub(crate)"))]
+    secret: u32,
+}
+
+fn readable<F: GetField<Example>>() {}
+
+fn main() {
+    let mut example = Example {
+        name: "ExampleName".to_string(),
+        id: 1,
+        secret: 2,
+    };
+
+    assert!(example.get_field("name").is_some());
+    assert!(example.set_field("name", Box::new("NewName".to_string())).is_ok());
+    readable::<example_fields::Name>();
+
+    assert!(example.get_field("id").is_some());
+    assert!(matches!(
+        example.set_field("id", Box::new(2u32)),
+        Err(ExampleReflectError::ReadOnly("id"))
+    ));
+    assert!(example.get_field_mut("id").is_none());
+    readable::<example_fields::Id>();
+
+    assert!(example.get_field("secret").is_none());
+    assert!(matches!(
+        example.set_field("secret", Box::new(3u32)),
+        Err(ExampleReflectError::UnknownField(_))
+    ));
+
+    let _ = (example.get_id(), example.get_secret());
+    example.set_id(3);
+    example.set_secret(4);
+}
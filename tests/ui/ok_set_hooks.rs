@@ -0,0 +1,60 @@
+use std::cell::Cell;
+
+use get_set_macro::get_set;
+
+#[derive(Debug, PartialEq)]
+struct TooOld(u32);
+
+#[get_set(reflect)]
+#[derive(Default)]
+struct Example {
+    // `set_name` returns whether the new value was accepted.
+    #[gsflags(get, set(before = "Self::non_empty", after = "Self::count"))]
+    name: String,
+
+    // `set_age` returns `Result<(), TooOld>`.
+    #[gsflags(get, set(before = "Self::check_age", error = "TooOld"))]
+    age: u32,
+
+    changes: Cell<u32>,
+}
+
+impl Example {
+    fn non_empty(&self, _old: &String, new: &String) -> bool {
+        !new.is_empty()
+    }
+
+    fn count(&self, old: &String, new: &String) {
+        assert_ne!(old, new);
+        self.changes.set(self.changes.get() + 1);
+    }
+
+    fn check_age(&self, _old: &u32, new: &u32) -> Result<(), TooOld> {
+        if *new > 150 {
+            Err(TooOld(*new))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    assert!(example.set_name("ExampleName".to_string()));
+    assert!(!example.set_name(String::new()));
+    assert_eq!("ExampleName", example.get_name().as_str());
+    assert_eq!(1, example.changes.get());
+
+    assert_eq!(Ok(()), example.set_age(55));
+    assert_eq!(Err(TooOld(200)), example.set_age(200));
+    assert_eq!(55, *example.get_age());
+
+    // Reflection goes through the same setters.
+    assert!(matches!(
+        example.set_field("age", Box::new(151u32)),
+        Err(ExampleReflectError::Vetoed("age"))
+    ));
+    assert!(example.set_field("name", Box::new("NewName".to_string())).is_ok());
+    assert_eq!(2, example.changes.get());
+}
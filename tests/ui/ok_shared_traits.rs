@@ -0,0 +1,61 @@
+use get_set_macro::{get_set, shared_traits};
+
+mod traits {
+    use get_set_macro::shared_traits;
+
+    shared_traits! {
+        pub age: u32,
+    }
+}
+
+shared_traits! {
+    name: String,
+    tag: &'static str,
+}
+
+#[get_set(get)]
+struct Person {
+    // Implements `HasName` and `HasNameMut`, the global `get` still generates an inherent `get_name`.
+    #[gsflags(get(shared_trait), set(shared_trait))]
+    name: String,
+
+    #[gsflags(get(shared_trait = "traits::HasAge"))]
+    age: u32,
+}
+
+#[get_set]
+struct Pet {
+    #[gsflags(get(shared_trait))]
+    name: String,
+
+    #[gsflags(get(shared_trait))]
+    tag: &'static str,
+}
+
+fn greet<T: HasName>(t: &T) -> String {
+    format!("Hello, {}!", t.get_name())
+}
+
+fn rename<T: HasNameMut>(t: &mut T, name: &str) {
+    t.set_name(name.to_string());
+}
+
+fn main() {
+    let mut person = Person {
+        name: "Alice".to_string(),
+        age: 30,
+    };
+    let pet = Pet {
+        name: "Rex".to_string(),
+        tag: "dog",
+    };
+
+    assert_eq!("Hello, Alice!", greet(&person));
+    assert_eq!("Hello, Rex!", greet(&pet));
+    assert_eq!("dog", HasTag::get_tag(&pet));
+    assert_eq!(30, *traits::HasAge::get_age(&person));
+
+    rename(&mut person, "Bob");
+
+    assert_eq!("Bob", person.get_name().as_str());
+}
@@ -0,0 +1,28 @@
+use get_set_macro::get_set;
+
+#[get_set]
+struct Example {
+    // Each attribute adds its functions, and all of them are removed.
+    #[gsflags(get)]
+    #[gsflags(set)]
+    name: String,
+
+    // Attributes other than `gsflags` are kept, wherever they are.
+    #[gsflags(get)]
+    #[gsflags(set(rename = "rename_age"))]
+    #[allow(dead_code)]
+    age: u32,
+}
+
+fn main() {
+    let mut example = Example {
+        name: "ExampleName".to_string(),
+        age: 55,
+    };
+
+    example.set_name("NewName".to_string());
+    assert_eq!("NewName", example.get_name());
+
+    example.rename_age(56);
+    assert_eq!(&56, example.get_age());
+}
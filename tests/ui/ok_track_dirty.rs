@@ -0,0 +1,64 @@
+use get_set_macro::get_set;
+
+#[get_set(track_dirty, set)]
+#[derive(Default, Debug, PartialEq)]
+struct Example {
+    name: String,
+
+    #[gsflags(get)]
+    age: u32,
+
+    #[gsflags(skip)]
+    skipped: f32,
+
+    // Skipped, but its own setter marks it like any other.
+    #[gsflags(skip, set)]
+    note: String,
+}
+
+#[get_set(track_dirty(skip_unchanged), set)]
+struct Unchanged {
+    value: u32,
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    assert!(!example.is_dirty());
+
+    example.set_age(55);
+    example.set_name("ExampleName".to_string());
+
+    assert!(example.is_name_dirty() && example.is_age_dirty());
+    assert_eq!(vec!["name", "age"], example.dirty_fields().collect::<Vec<_>>());
+
+    example.clear_dirty();
+
+    assert!(!example.is_dirty());
+    assert_eq!(0, example.dirty_fields().count());
+
+    example.set_note("Note".to_string());
+    assert!(example.is_note_dirty() && !example.is_skipped_dirty());
+    assert_eq!(vec!["note"], example.dirty_fields().collect::<Vec<_>>());
+
+    // The bitset never affects comparisons.
+    let mut same = Example::default();
+    same.age = 55;
+    same.name = "ExampleName".to_string();
+    same.note = "Note".to_string();
+    assert_eq!(same, example);
+
+    // Constructing the struct by hand requires the hidden bitset.
+    let mut unchanged = Unchanged {
+        value: 1,
+        __dirty: Default::default(),
+    };
+
+    unchanged.set_value(1);
+    assert!(!unchanged.is_value_dirty());
+
+    unchanged.set_value(2);
+    assert!(unchanged.is_value_dirty());
+
+    let _ = example.skipped;
+}
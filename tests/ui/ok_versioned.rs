@@ -0,0 +1,66 @@
+use get_set_macro::get_set;
+
+#[get_set(versioned(per_field), history, set)]
+#[derive(Default, Debug, PartialEq, Eq, Hash)]
+struct Example {
+    #[gsflags(history)]
+    name: String,
+    age: u32,
+
+    // Skipped, but its setter still bumps its version.
+    #[gsflags(skip, set)]
+    id: u8,
+}
+
+// Uses its own `version` field as the counter.
+#[get_set(versioned, track_dirty(skip_unchanged), set)]
+#[derive(Default)]
+struct Counted {
+    value: u8,
+    version: u64,
+}
+
+#[get_set(versioned, set)]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {
+    let mut example = Example::default();
+    assert_eq!(0, example.version());
+
+    example.set_name("ExampleName".to_string());
+    example.set_age(55);
+    example.set_age(56);
+    assert_eq!(3, example.version());
+    assert_eq!(1, example.name_version());
+    assert_eq!(2, example.age_version());
+
+    // Undo is a write too.
+    example.undo();
+    assert_eq!(4, example.version());
+    assert_eq!(2, example.name_version());
+
+    example.set_id(1);
+    assert_eq!(1, example.id_version());
+
+    // The versions never affect comparisons.
+    let mut same = Example::default();
+    same.set_age(56);
+    same.id = 1;
+    assert_eq!(same, example);
+
+    let mut counted = Counted::default();
+    counted.set_value(1);
+    // Unchanged writes are not counted with `skip_unchanged`.
+    counted.set_value(1);
+    assert_eq!(1, counted.version());
+    assert_eq!(1, counted.version);
+
+    let mut generic = Generic {
+        value: 'a',
+        __version: Default::default(),
+    };
+    generic.set_value('b');
+    assert_eq!(1, generic.version());
+}
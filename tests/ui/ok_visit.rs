@@ -0,0 +1,60 @@
+use std::fmt::Debug;
+
+use get_set_macro::get_set;
+
+#[get_set(visit, get)]
+struct Example<T: Debug> {
+    #[gsflags(set)]
+    name: String,
+
+    age: u32,
+
+    value: T,
+
+    #[gsflags(skip)]
+    skipped: f32,
+}
+
+// Handles every field through the generic method.
+#[derive(Default)]
+struct Logger(Vec<String>);
+
+impl<T: Debug> ExampleVisitor<T> for Logger {
+    fn visit_field<V: ?Sized + Debug>(&mut self, name: &'static str, value: &V) {
+        self.0.push(format!("{name}: {value:?}"));
+    }
+}
+
+// Handles a single field through its typed method.
+struct Upper;
+
+impl<T: Debug> ExampleVisitorMut<T> for Upper {
+    fn visit_name_mut(&mut self, value: &mut String) {
+        *value = value.to_uppercase();
+    }
+}
+
+fn main() {
+    let mut example = Example {
+        name: "ExampleName".to_string(),
+        age: 55,
+        value: 'x',
+        skipped: 1.0,
+    };
+
+    example.visit_fields_mut(&mut Upper);
+
+    let mut logger = Logger::default();
+    example.visit_fields(&mut logger);
+
+    assert_eq!(
+        vec![
+            "name: \"EXAMPLENAME\"".to_string(),
+            "age: 55".to_string(),
+            "value: 'x'".to_string(),
+        ],
+        logger.0
+    );
+
+    let _ = example.skipped;
+}